@@ -0,0 +1,89 @@
+extern crate aoc_core;
+
+use aoc_core::{Error, Solution};
+
+pub fn eval_parens(parens: &str) -> (i32, usize) {
+    let mut floor = 0;
+    let mut position = 0;
+    let mut found = false;
+
+    // Iterate through characters in parens string. Increment or decrement
+    // accordingly. Do nothing if another character is found (i.e. '\n').
+    for (i, c) in parens.chars().enumerate() {
+        match c {
+            '(' => floor += 1,
+            ')' => floor -= 1,
+            _ => { },
+        }
+
+        // Check to see if we've entered the basement yet.
+        if floor == -1 && !found {
+            position = i + 1;
+            found = true;
+        }
+    }
+    (floor , position)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+
+    type Input = String;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Error> {
+        Ok(eval_parens(input).0)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Error> {
+        Ok(eval_parens(input).1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::eval_parens;
+
+    #[test]
+    fn test_floor_zero() {
+        assert_eq!(eval_parens("(())").0, 0);
+        assert_eq!(eval_parens("()()").0, 0);
+    }
+
+    #[test]
+    fn test_floor_three() {
+        assert_eq!(eval_parens("(((").0, 3);
+        assert_eq!(eval_parens("(()(()(").0, 3);
+        assert_eq!(eval_parens("))(((((").0, 3);
+    }
+
+    #[test]
+    fn test_floor_negative_one() {
+        assert_eq!(eval_parens("())").0, -1);
+        assert_eq!(eval_parens("))(").0, -1);
+    }
+
+    #[test]
+    fn test_floor_negative_three() {
+        assert_eq!(eval_parens(")))").0, -3);
+        assert_eq!(eval_parens(")())())").0, -3);
+    }
+
+    #[test]
+    fn test_position_one() {
+        assert_eq!(eval_parens(")").1, 1);
+    }
+
+    #[test]
+    fn test_position_five() {
+        assert_eq!(eval_parens("()())").1, 5);
+    }
+}
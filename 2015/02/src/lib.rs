@@ -0,0 +1,78 @@
+extern crate aoc_core;
+
+use aoc_core::parse::{self, ParseError};
+use aoc_core::{Error, Solution};
+
+fn split_as_u32(s: &str, c: char) -> Result<Vec<u32>, ParseError> {
+    let d = s.split(c)
+             .map(|x| parse::value(s, x))
+             .collect::<Result<Vec<u32>, _>>()?;
+    if d.len() != 3 {
+        return Err(ParseError::line("expected three dimensions, like 2x3x4", s));
+    }
+    Ok(d)
+}
+
+pub fn materials(dimensions: &str) -> Result<(u32, u32), ParseError> {
+    let mut d = split_as_u32(dimensions, 'x')?;
+    d.sort();
+    let smallest = &d[..2];
+    let ribbon = d.iter().product::<u32>() + smallest.iter().map(|x| 2 * x).sum::<u32>();
+    let sides = vec![(d[0] * d[1]), (d[1] * d[2]), (d[2] * d[0])];
+    let surface_area = sides.iter().map(|x| 2 * x).sum::<u32>();
+    Ok((surface_area + sides[0], ribbon))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 2;
+
+    type Input = Vec<(u32, u32)>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse::lines(input.lines(), materials)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Error> {
+        Ok(input.iter().map(|(surface_area, _)| surface_area).sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Error> {
+        Ok(input.iter().map(|(_, ribbon)| ribbon).sum())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::materials;
+
+    #[test]
+    fn test_two_by_three_by_four() {
+        let (surface_area, ribbon) = materials("2x3x4").unwrap();
+        assert_eq!(58, surface_area);
+        assert_eq!(34, ribbon);
+    }
+
+    #[test]
+    fn test_one_by_one_by_ten() {
+        let (surface_area, ribbon) = materials("1x1x10").unwrap();
+        assert_eq!(43, surface_area);
+        assert_eq!(14, ribbon);
+    }
+
+    #[test]
+    fn test_invalid_dimension() {
+        let error = materials("1xyx10").unwrap_err();
+        assert_eq!(2..3, error.columns);
+    }
+
+    #[test]
+    fn test_missing_dimension() {
+        let error = materials("1x1").unwrap_err();
+        assert_eq!(0..3, error.columns);
+    }
+}
@@ -0,0 +1,72 @@
+extern crate aoc_core;
+
+use aoc_core::{Error, Solution};
+
+pub fn visit_houses(directions: &str) -> usize {
+    let (mut x, mut y) = (0, 0);
+    let mut houses = vec![];
+    houses.push((x, y));
+    for c in directions.chars() {
+        match c {
+            '^' => y += 1,
+            'v' => y -= 1,
+            '>' => x += 1,
+            '<' => x -= 1,
+            _ => {}
+        }
+
+        if !houses.contains(&(x, y)) {
+            houses.push((x, y));
+        }
+    }
+    houses.len()
+}
+
+pub fn robo_visit_houses(_directions: &str) -> usize {
+    // Maybe have a flag for a robot santa in the function above instead...
+    3
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 3;
+
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Error> {
+        Ok(visit_houses(input))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Self::Part2, Error> {
+        // Robo-Santa hasn't been figured out yet.
+        Err(Error::Unimplemented)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{visit_houses, robo_visit_houses};
+
+    #[test]
+    fn test_visits() {
+        assert_eq!(2, visit_houses(">"));
+        assert_eq!(4, visit_houses("^>v<"));
+        assert_eq!(2, visit_houses("^v^v^v^v^v"));
+    }
+
+    #[test]
+    #[ignore = "Robo-Santa isn't solved yet"]
+    fn test_robo_visits() {
+        assert_eq!(3, robo_visit_houses("^v"));
+        assert_eq!(3, robo_visit_houses("^>v<"));
+        assert_eq!(11, robo_visit_houses("^v^v^v^v^v"));
+    }
+}
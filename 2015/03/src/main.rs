@@ -1,71 +1,10 @@
-use std::env;
-use std::error::Error;
-use std::fs::File;
-use std::io::prelude::*;
-use std::path::Path;
+extern crate aoc_2015_03;
+extern crate aoc_core;
 
-fn visit_houses(directions: &str) -> usize {
-    let (mut x, mut y) = (0, 0);
-    let mut houses = vec![];
-    houses.push((x, y));
-    for c in directions.chars() {
-        match c {
-            '^' => y += 1,
-            'v' => y -= 1,
-            '>' => x += 1,
-            '<' => x -= 1,
-            _ => {}
-        }
+use std::process::ExitCode;
 
-        if !houses.contains(&(x, y)) {
-            houses.push((x, y));
-        }
-    }
-    houses.len()
-}
-
-fn robo_visit_houses(directions: &str) -> usize {
-    // Maybe have a flag for a robot santa in the function above instead...
-    3
-}
-
-fn main() {
-    let input = env::args().nth(1).unwrap();
-
-    let path = Path::new(&input);
-    let display = path.display();
-
-    let mut file = match File::open(&path) {
-        Err(why) => panic!("couldn't open {}: {}", display, Error::description(&why)),
-        Ok(file) => file,
-    };
-
-    let mut s = String::new();
-    match file.read_to_string(&mut s) {
-        Err(why) => panic!("couldn't read {}: {}", display, Error::description(&why)),
-        Ok(_) => {
-            let at_least_one_present = visit_houses(&s);
-            println!("{} houses receive at least one present.",
-                     at_least_one_present);
-        }
-    }
-}
-
-#[cfg(test)]
-mod test {
-    use super::{visit_houses, robo_visit_houses};
-
-    #[test]
-    fn test_visits() {
-        assert_eq!(2, visit_houses(">"));
-        assert_eq!(4, visit_houses("^>v<"));
-        assert_eq!(2, visit_houses("^v^v^v^v^v"));
-    }
+use aoc_2015_03::Puzzle;
 
-    #[test]
-    fn test_robo_visits() {
-        assert_eq!(3, robo_visit_houses("^v"));
-        assert_eq!(3, robo_visit_houses("^>v<"));
-        assert_eq!(11, robo_visit_houses("^v^v^v^v^v"));
-    }
+fn main() -> ExitCode {
+    aoc_core::cli::main::<Puzzle>()
 }
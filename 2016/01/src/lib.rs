@@ -0,0 +1,178 @@
+extern crate aoc_core;
+
+use std::collections::HashSet;
+
+use aoc_core::parse::{self, ParseError};
+use aoc_core::{Error, Solution};
+
+#[derive(Debug, Copy, Clone)]
+enum Direction {
+    North,
+    East,
+    West,
+    South,
+}
+
+#[derive(Debug)]
+enum Turn {
+    Left(Direction),
+    Right(Direction),
+}
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    turn: String,
+    blocks: i64,
+}
+
+pub fn parse_instructions(instructions: &str) -> Result<Vec<Instruction>, ParseError> {
+    instructions.split(", ")
+        .map(|string| {
+            if !(string.starts_with('L') || string.starts_with('R')) {
+                return Err(ParseError::new("expected an instruction like R2 or L3",
+                                           instructions,
+                                           string));
+            }
+            let (turn, blocks) = string.split_at(1);
+            Ok(Instruction {
+                turn: turn.to_string(),
+                blocks: parse::value(instructions, blocks)?,
+            })
+        })
+        .collect()
+}
+
+fn interpolate(old: (i64, i64), new: (i64, i64)) -> Vec<(i64, i64)> {
+    // Note: NOT OK to iterate backwards.
+    let (x1, y1) = old;
+    let (x2, y2) = new;
+
+
+    // The two x coordinates are equal, so we interpolate with y coordinates.
+    if x1 == x2 {
+        if y1 < y2 { y1..y2 } else { y2..y1 }
+            .map(|i| (x1, i))
+            .collect()
+
+    // The two y coordinates are equal, so we interpolate with x coordinates.
+    } else {
+        if x1 < x2 { x1..x2 } else { x2..x1 }
+            .map(|i| (i, y1))
+            .collect()
+    }
+}
+
+pub fn navigate(instructions: Vec<Instruction>) -> (i64, i64) {
+    let mut location = (0i64, 0i64);
+    let mut previous_location: (i64, i64);
+    let mut direction = Direction::North;
+
+    let mut found = false;
+    let mut actual_distance = 0;
+    let mut locations = HashSet::new();
+
+    // Record the first location in case we come back to it!
+    // locations.insert(location.clone());
+
+    for instruction in instructions {
+
+        let turn = match &instruction.turn[..] {
+            "L" => Turn::Left(direction),
+            "R" => Turn::Right(direction),
+            _ => panic!("Invalid instruction received"),
+        };
+
+        previous_location = location;
+
+        match turn {
+            Turn::Left(Direction::North) |
+            Turn::Right(Direction::South) => {
+                location.0 -= instruction.blocks;
+                direction = Direction::West;
+            }
+            Turn::Right(Direction::North) |
+            Turn::Left(Direction::South) => {
+                location.0 += instruction.blocks;
+                direction = Direction::East;
+            }
+            Turn::Left(Direction::East) |
+            Turn::Right(Direction::West) => {
+                location.1 += instruction.blocks;
+                direction = Direction::North;
+            }
+            Turn::Right(Direction::East) |
+            Turn::Left(Direction::West) => {
+                location.1 -= instruction.blocks;
+                direction = Direction::South;
+            }
+        }
+
+        if !found {
+            let coordinates = interpolate(previous_location, location);
+            for coord in coordinates {
+                if locations.contains(&coord) {
+                    actual_distance = location.0.abs() + location.1.abs();
+                    found = true;
+                } else {
+                    locations.insert(coord);
+                };
+            }
+        };
+    }
+    (location.0.abs() + location.1.abs(), actual_distance)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 1;
+
+    type Input = Vec<Instruction>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse_instructions(input.trim())?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Error> {
+        Ok(navigate(input.clone()).0)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Error> {
+        Ok(navigate(input.clone()).1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_instructions, navigate};
+
+    #[test]
+    fn test_navigation() {
+
+        let expectations = vec![
+            (5, parse_instructions("R2, L3").unwrap()),
+            (2, parse_instructions("R2, R2, R2").unwrap()),
+            (12, parse_instructions("R5, L5, R5, R3").unwrap()),
+        ];
+
+        for (result, instructions) in expectations {
+            assert_eq!(result, navigate(instructions).0);
+        }
+    }
+
+    #[test]
+    fn test_invalid_instructions() {
+        let expectations = vec![
+            (4..6, "R2, U3"),
+            (5..6, "R2, Lx"),
+            (4..4, "R2, "),
+        ];
+
+        for (columns, instructions) in expectations {
+            assert_eq!(columns, parse_instructions(instructions).unwrap_err().columns);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+extern crate aoc_core;
+
+use aoc_core::{Error, Solution};
+
+pub fn captcha(input: &str) -> u32 {
+    input
+        .chars()
+        .zip(input.chars().skip(1).chain(input.chars().nth(0)))
+        .filter_map(|t| match (t.0, t.1) {
+            (x, y) if x == y => Some(x.to_digit(10)?),
+            _ => None,
+        })
+        .sum()
+}
+
+pub fn recaptcha(input: &str) -> u32 {
+    input
+        .chars()
+        .zip(input.chars().cycle().skip(input.len() / 2).take(
+            input.len(),
+        ))
+        .filter_map(|t| match (t.0, t.1) {
+            (x, y) if x == y => Some(x.to_digit(10)?),
+            _ => None,
+        })
+        .sum()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2017;
+    const DAY: u8 = 1;
+
+    type Input = String;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Error> {
+        Ok(captcha(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Error> {
+        Ok(recaptcha(input))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use {captcha, recaptcha};
+
+    #[test]
+    fn test_captcha() {
+        let givens = vec!["1122", "1111", "1234", "91212129"];
+        let expected = vec![3, 4, 0, 9];
+        let actuals: Vec<u32> = givens.iter().map(|g| captcha(&g)).collect();
+
+        assert_eq!(expected, actuals);
+
+    }
+
+    #[test]
+    fn test_recaptcha() {
+        let givens = vec!["1212", "1221", "123425", "123123", "12131415"];
+        let expected = vec![6, 0, 4, 12, 4];
+        let actuals: Vec<u32> = givens.iter().map(|g| recaptcha(&g)).collect();
+
+        assert_eq!(expected, actuals);
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashSet;
+
+use aoc_core::parse::{self, ParseError};
+use aoc_core::{Error, Solution};
+
+pub fn parse<'a>(
+    changes: impl Iterator<Item = &'a (impl AsRef<str> + 'a)>,
+) -> Result<Vec<isize>, ParseError> {
+    parse::lines(changes, |change| parse::value(change, change))
+}
+
+pub fn frequency<'a>(changes: impl Iterator<Item = &'a isize>) -> isize {
+    changes.sum()
+}
+
+pub fn first_repeat<'a>(changes: impl Iterator<Item = &'a isize> + Clone) -> Option<isize> {
+    let mut frequency = 0;
+    let mut frequencies = HashSet::new();
+    frequencies.insert(frequency);
+    for change in changes.cycle() {
+        frequency += change;
+        if frequencies.contains(&frequency) {
+            return Some(frequency);
+        }
+        frequencies.insert(frequency);
+    }
+    None
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 1;
+
+    type Input = Vec<isize>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse(input.lines().collect::<Vec<_>>().iter())?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Error> {
+        Ok(frequency(input.iter()))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Error> {
+        first_repeat(input.iter()).ok_or(Error::msg("no frequency changes were given"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{first_repeat, frequency, parse};
+
+    #[test]
+    fn parse_errors_with_invalid_digits() {
+        let invalid_digits = vec!["", "a", "$", "‽"];
+        for invalid_digit in invalid_digits {
+            assert!(parse(vec![invalid_digit].iter()).is_err());
+        }
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_invalid_line() {
+        let error = parse(["+1", "+1", "+a"].iter()).unwrap_err();
+        assert_eq!(3, error.line);
+        assert_eq!("+a", error.offending());
+    }
+
+    #[test]
+    fn parse_handles_positive_digits() {
+        let digits = vec!["+1", "+1", "+1"];
+        let expected = Ok(vec![1, 1, 1]);
+        assert_eq!(expected, parse(digits.iter()));
+    }
+
+    #[test]
+    fn parse_handles_negative_digits() {
+        let digits = vec!["-1", "-2", "-3"];
+        let expected = Ok(vec![-1, -2, -3]);
+        assert_eq!(expected, parse(digits.iter()));
+    }
+
+    #[test]
+    fn parse_handles_positive_and_negative_digits() {
+        let digits = vec!["+1", "+1", "-2"];
+        let expected = Ok(vec![1, 1, -2]);
+        assert_eq!(expected, parse(digits.iter()));
+    }
+
+    #[test]
+    fn frequency_is_zero_with_no_changes() {
+        let changes: Vec<isize> = vec![];
+        assert_eq!(0, frequency(changes.iter()));
+    }
+
+    #[test]
+    fn frequency_handles_positive_changes() {
+        let changes = vec![1, 1, 1];
+        assert_eq!(3, frequency(changes.iter()));
+    }
+
+    #[test]
+    fn frequency_handles_negative_changes() {
+        let changes = vec![-1, -2, -3];
+        assert_eq!(-6, frequency(changes.iter()));
+    }
+
+    #[test]
+    fn frequency_handles_positive_and_negative_changes() {
+        let changes = vec![1, 1, -2];
+        assert_eq!(0, frequency(changes.iter()));
+    }
+
+    #[test]
+    fn first_repeat_is_none_with_no_changes() {
+        let changes = vec![];
+        assert_eq!(None, first_repeat(changes.iter()));
+    }
+
+    #[test]
+    fn first_repeat_handles_finite_input() {
+        let inputs = vec![
+            (vec![1, -1], Some(0)),
+            (vec![3, 3, 4, -2, -4], Some(10)),
+            (vec![-6, 3, 8, 5, -6], Some(5)),
+            (vec![7, 7, -2, -7, -4], Some(14)),
+        ];
+        for (given, expected) in inputs {
+            assert_eq!(expected, first_repeat(given.iter()));
+        }
+    }
+}
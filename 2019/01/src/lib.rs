@@ -0,0 +1,71 @@
+use aoc_core::{parse, Error, Solution};
+
+/// Determine total fuel required for the given mass.
+pub fn fuel(mass: &isize) -> isize {
+    (mass / 3) - 2
+}
+
+/// Include fuel costs in determining total fuel required for the given mass.
+pub fn factor_fuel(mass: &isize) -> isize {
+    let fuel = (mass / 3) - 2;
+    match fuel <= 0 {
+        true => 0,
+        false => fuel + factor_fuel(&fuel),
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 1;
+
+    type Input = Vec<isize>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse::lines(input.lines(), |line| parse::value(line, line))?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Error> {
+        Ok(input.iter().map(fuel).sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Error> {
+        Ok(input.iter().map(factor_fuel).sum())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{factor_fuel, fuel};
+
+    #[test]
+    fn fuel_handles_example_inputs() {
+        let inputs = vec![
+            (&12isize, 2isize),
+            (&14isize, 2isize),
+            (&1_969isize, 654isize),
+            (&100_756isize, 33_583isize),
+        ];
+
+        for (given, expected) in inputs {
+            assert_eq!(expected, fuel(given));
+        }
+    }
+
+    #[test]
+    fn factor_fuel_handles_example_inputs() {
+        let inputs = vec![
+            (&12isize, 2isize),
+            (&14isize, 2isize),
+            (&1_969isize, 966isize),
+            (&100_756isize, 50_346isize),
+        ];
+
+        for (given, expected) in inputs {
+            assert_eq!(expected, factor_fuel(given));
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use aoc_2019_02::{
+    intcode::{compiled::Compiled, Status, Vm},
+    parse,
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const TARGET: i64 = 19690720;
+
+// Every noun and verb part 2 might try, in order.
+fn nouns_and_verbs() -> impl Iterator<Item = (i64, i64)> {
+    (0..100).flat_map(|noun| (0..100).map(move |verb| (noun, verb)))
+}
+
+fn interpreted(program: &[i64]) -> Option<(i64, i64)> {
+    nouns_and_verbs().find(|&(noun, verb)| {
+        let mut vm = Vm::new(program.to_vec());
+        vm.store(1, noun).and_then(|()| vm.store(2, verb)).is_ok()
+            && vm.run(&mut ()) == Ok(Status::Halted)
+            && vm.memory()[0] == TARGET
+    })
+}
+
+fn compiled(program: &[i64]) -> Option<(i64, i64)> {
+    let compiled = Compiled::new(Vm::new(program.to_vec()));
+    let mut vm = compiled.clone();
+    nouns_and_verbs().find(|&(noun, verb)| {
+        vm.clone_from(&compiled);
+        vm.store(1, noun).and_then(|()| vm.store(2, verb)).is_ok()
+            && vm.run(&mut ()) == Ok(Status::Halted)
+            && vm.vm().memory()[0] == TARGET
+    })
+}
+
+// Search for part 2's noun and verb by running the program with each in turn,
+// like it was solved before being solved symbolically, on each engine. Named
+// like the runner's benchmarks so `cargo bench -- 2019/02` finds them too.
+fn search(c: &mut Criterion) {
+    let program = parse(include_str!("../input.txt")).expect("input.txt should parse");
+    assert_eq!(interpreted(&program), compiled(&program));
+
+    let mut group = c.benchmark_group("2019/02/search");
+    group.bench_function("interpreted", |b| b.iter(|| interpreted(&program)));
+    group.bench_function("compiled", |b| b.iter(|| compiled(&program)));
+    group.finish();
+}
+
+criterion_group!(benches, search);
+criterion_main!(benches);
@@ -0,0 +1,14 @@
+#![no_main]
+
+use aoc_2019_02::intcode::{io::Queue, Vm};
+use libfuzzer_sys::fuzz_target;
+
+// Any program, with any input, either runs until it stops or fails with an
+// error rather than panicking. Programs which loop forever run out of budget.
+fuzz_target!(|data: (Vec<i64>, Vec<i64>)| {
+    let (program, input) = data;
+    let mut vm = Vm::new(program)
+        .with_memory_limit(1 << 16)
+        .with_budget(10_000);
+    let _ = vm.run(&mut Queue::new(input));
+});
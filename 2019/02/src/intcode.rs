@@ -0,0 +1,975 @@
+pub mod ascii;
+pub mod asm;
+pub mod compiled;
+pub mod debug;
+pub mod disasm;
+pub mod io;
+pub mod memory;
+pub mod network;
+pub mod search;
+pub mod snapshot;
+pub mod trace;
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+pub use io::Io;
+pub use memory::Memory;
+pub use snapshot::Snapshot;
+pub use trace::Trace;
+
+/// How far memory may grow unless told otherwise, in words.
+pub const MEMORY_LIMIT: usize = 1 << 20;
+
+/// How many instructions [`run`] executes before giving up on a program ever
+/// halting.
+pub const BUDGET: u64 = 10_000_000;
+
+/// How an instruction's parameter is interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// The parameter is the address of a value.
+    Position,
+    /// The parameter is the value itself.
+    Immediate,
+    /// The parameter is the address of a value relative to the relative base.
+    Relative,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    Add,
+    Multiply,
+    Input,
+    Output,
+    JumpIfTrue,
+    JumpIfFalse,
+    LessThan,
+    Equals,
+    AdjustRelativeBase,
+    Halt,
+}
+
+impl Opcode {
+    pub const ALL: [Self; 10] = [
+        Self::Add,
+        Self::Multiply,
+        Self::Input,
+        Self::Output,
+        Self::JumpIfTrue,
+        Self::JumpIfFalse,
+        Self::LessThan,
+        Self::Equals,
+        Self::AdjustRelativeBase,
+        Self::Halt,
+    ];
+
+    /// The last two digits of an instruction with this opcode.
+    pub fn code(self) -> i64 {
+        match self {
+            Self::Add => 1,
+            Self::Multiply => 2,
+            Self::Input => 3,
+            Self::Output => 4,
+            Self::JumpIfTrue => 5,
+            Self::JumpIfFalse => 6,
+            Self::LessThan => 7,
+            Self::Equals => 8,
+            Self::AdjustRelativeBase => 9,
+            Self::Halt => 99,
+        }
+    }
+
+    /// How many parameters follow the opcode.
+    pub fn parameters(self) -> usize {
+        match self {
+            Self::Add | Self::Multiply | Self::LessThan | Self::Equals => 3,
+            Self::JumpIfTrue | Self::JumpIfFalse => 2,
+            Self::Input | Self::Output | Self::AdjustRelativeBase => 1,
+            Self::Halt => 0,
+        }
+    }
+
+    /// How many values the whole instruction takes up in memory.
+    pub fn width(self) -> usize {
+        1 + self.parameters()
+    }
+
+    /// Which parameter, if any, is the address the instruction writes to.
+    pub fn writes(self) -> Option<usize> {
+        match self {
+            Self::Add | Self::Multiply | Self::LessThan | Self::Equals => Some(2),
+            Self::Input => Some(0),
+            _ => None,
+        }
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Self::Add => "ADD",
+            Self::Multiply => "MUL",
+            Self::Input => "IN",
+            Self::Output => "OUT",
+            Self::JumpIfTrue => "JT",
+            Self::JumpIfFalse => "JF",
+            Self::LessThan => "LT",
+            Self::Equals => "EQ",
+            Self::AdjustRelativeBase => "ARB",
+            Self::Halt => "HALT",
+        }
+    }
+}
+
+impl fmt::Display for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.mnemonic())
+    }
+}
+
+/// An opcode along with the mode of each of its parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub modes: [Mode; 3],
+}
+
+impl Instruction {
+    /// Decode an instruction like `1002`, where the last two digits are the
+    /// opcode and the rest are the modes of the parameters from right to left.
+    /// Errors say it came from the given address.
+    pub fn decode(address: usize, value: i64) -> Result<Self, Error> {
+        let opcode = match value % 100 {
+            _ if value < 0 => return Err(Error::Opcode { address, value }),
+            1 => Opcode::Add,
+            2 => Opcode::Multiply,
+            3 => Opcode::Input,
+            4 => Opcode::Output,
+            5 => Opcode::JumpIfTrue,
+            6 => Opcode::JumpIfFalse,
+            7 => Opcode::LessThan,
+            8 => Opcode::Equals,
+            9 => Opcode::AdjustRelativeBase,
+            99 => Opcode::Halt,
+            _ => return Err(Error::Opcode { address, value }),
+        };
+
+        let mut modes = [Mode::Position; 3];
+        let mut digits = value / 100;
+        for mode in &mut modes {
+            *mode = match digits % 10 {
+                0 => Mode::Position,
+                1 => Mode::Immediate,
+                2 => Mode::Relative,
+                _ => return Err(Error::Mode { address, value }),
+            };
+            digits /= 10;
+        }
+        if digits != 0 {
+            return Err(Error::Mode { address, value });
+        }
+
+        Ok(Self { opcode, modes })
+    }
+
+    /// The inverse of [`Instruction::decode`].
+    pub fn encode(self) -> i64 {
+        let modes = self.modes.iter().rev().fold(0, |digits, mode| {
+            let digit = match mode {
+                Mode::Position => 0,
+                Mode::Immediate => 1,
+                Mode::Relative => 2,
+            };
+            digits * 10 + digit
+        });
+        modes * 100 + self.opcode.code()
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum Error {
+    #[error("Instruction {value} at {address} has an invalid opcode")]
+    Opcode { address: usize, value: i64 },
+    #[error("Instruction {value} at {address} has an invalid parameter mode")]
+    Mode { address: usize, value: i64 },
+    #[error("Instruction {value} at {address} writes in immediate mode")]
+    ImmediateWrite { address: usize, value: i64 },
+    #[error("Invalid address: {0}")]
+    Address(i64),
+    #[error("Address {address} is beyond the memory limit of {limit}")]
+    MemoryLimit { address: i64, limit: usize },
+    #[error("{opcode} at {address} moved the pointer to {pointer}, outside of memory")]
+    PointerOutOfBounds {
+        address: usize,
+        opcode: Opcode,
+        pointer: i64,
+    },
+    #[error("{opcode} at {address} refers to address {operand}, which is out of bounds")]
+    OperandOutOfBounds {
+        address: usize,
+        opcode: Opcode,
+        operand: i64,
+    },
+    #[error("The pointer is at {0}, past the end of memory")]
+    PointerPastEnd(usize),
+    #[error("{opcode} at {address} runs past the end of memory")]
+    TruncatedInstruction { address: usize, opcode: Opcode },
+    #[error("{opcode} at {address} overflowed")]
+    Overflow { address: usize, opcode: Opcode },
+    #[error("Gave up at {address} after executing {executed} instructions")]
+    Budget { address: usize, executed: u64 },
+    #[error("Stuck in an infinite loop starting at {0}")]
+    Loop(usize),
+    #[error("Instruction at {0} is waiting for input")]
+    NoInput(usize),
+    #[error("I/O failed: {0}")]
+    Io(String),
+}
+
+/// Whether the computer can keep running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Running,
+    /// Waiting for input which isn't there yet. Running again once there is
+    /// input resumes from the same instruction.
+    Blocked,
+    Halted,
+}
+
+/// An Intcode computer.
+///
+/// Memory beyond the end of the program reads as zero and grows to fit
+/// whatever is written there, up to a limit.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Vm {
+    memory: Memory,
+    pointer: usize,
+    relative_base: i64,
+    limit: usize,
+    executed: u64,
+    budget: Option<u64>,
+    #[serde(default)]
+    detect_loops: bool,
+    /// How far looking for loops has got. It isn't saved with the rest.
+    #[serde(skip)]
+    loops: Loops,
+}
+
+// A pointer, relative base and memory.
+type State = (usize, i64, Memory);
+
+/// Looks for loops with Brent's algorithm, which compares each state with a
+/// single checkpoint, moved on whenever the number of states since it reaches
+/// the next power of two. A loop is found within a couple of times round it,
+/// and is then gone through again from the last input to find where it
+/// starts.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Loops {
+    /// The first state since the last input.
+    origin: Option<State>,
+    checkpoint: Option<State>,
+    /// How many states there have been since the checkpoint.
+    since: u64,
+    /// How many states there can be before moving the checkpoint on.
+    power: u64,
+}
+
+impl Clone for Vm {
+    fn clone(&self) -> Self {
+        Self {
+            memory: self.memory.clone(),
+            loops: self.loops.clone(),
+            ..*self
+        }
+    }
+
+    /// Copy another computer into this one, reusing its memory as
+    /// [`Memory::clone_from`] does.
+    fn clone_from(&mut self, source: &Self) {
+        let memory = std::mem::take(&mut self.memory);
+        *self = Self {
+            memory,
+            loops: source.loops.clone(),
+            ..*source
+        };
+        self.memory.clone_from(&source.memory);
+    }
+}
+
+impl Vm {
+    pub fn new(program: Vec<i64>) -> Self {
+        Self {
+            memory: program.into(),
+            pointer: 0,
+            relative_base: 0,
+            limit: MEMORY_LIMIT,
+            executed: 0,
+            budget: None,
+            detect_loops: false,
+            loops: Loops::default(),
+        }
+    }
+
+    /// Limit how far memory may grow, in words.
+    pub fn with_memory_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Limit how many instructions may be executed in all, after which
+    /// executing another is an error.
+    pub fn with_budget(mut self, budget: u64) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Stop with an error as soon as the computer is in exactly the same
+    /// state as it was before, with the same pointer, relative base and
+    /// memory, since it would only go round the same loop forever.
+    ///
+    /// Reading input can change what happens next, so only states since the
+    /// last input count. Rather than keeping every state, only two are kept,
+    /// sharing memory with the computer until it writes to it. The loop is
+    /// found within a couple of times round it, and the error says where it
+    /// starts.
+    pub fn with_loop_detection(mut self) -> Self {
+        self.detect_loops = true;
+        self
+    }
+
+    pub fn memory(&self) -> &Memory {
+        &self.memory
+    }
+
+    pub fn into_memory(self) -> Vec<i64> {
+        self.memory.into()
+    }
+
+    /// The address of the current instruction.
+    pub fn pointer(&self) -> usize {
+        self.pointer
+    }
+
+    /// What relative mode parameters are relative to.
+    pub fn relative_base(&self) -> i64 {
+        self.relative_base
+    }
+
+    /// How many instructions have been executed.
+    pub fn executed(&self) -> u64 {
+        self.executed
+    }
+
+    /// Execute a single instruction.
+    pub fn step(&mut self, io: &mut impl Io) -> Result<Status, Error> {
+        self.step_traced(io, &mut ())
+    }
+
+    /// Execute a single instruction, telling a tracer what it reads and
+    /// writes, and why it failed if it does.
+    pub fn step_traced(
+        &mut self,
+        io: &mut impl Io,
+        trace: &mut impl Trace,
+    ) -> Result<Status, Error> {
+        let status = self.execute(io, trace);
+        if let Err(error) = &status {
+            // Failing to trace the error matters less than the error itself.
+            let _ = trace.failed(error);
+        }
+        status
+    }
+
+    fn execute(&mut self, io: &mut impl Io, trace: &mut impl Trace) -> Result<Status, Error> {
+        if self.pointer >= self.memory.len() {
+            return Err(Error::PointerPastEnd(self.pointer));
+        }
+        let instruction = Instruction::decode(self.pointer, self.memory[self.pointer])?;
+
+        // Work out where the next instruction is before executing this one,
+        // which might overwrite itself. Jumps go wherever they're told, even
+        // if that's right back to the same instruction.
+        let mut next = self.pointer + instruction.opcode.width();
+        if next > self.memory.len() {
+            return Err(Error::TruncatedInstruction {
+                address: self.pointer,
+                opcode: instruction.opcode,
+            });
+        }
+        trace.instruction(self, instruction);
+        if self.budget.is_some_and(|budget| self.executed >= budget) {
+            return Err(Error::Budget {
+                address: self.pointer,
+                executed: self.executed,
+            });
+        }
+
+        match instruction.opcode {
+            Opcode::Add => {
+                let left_addend = self.read(&instruction, 0, trace)?;
+                let right_addend = self.read(&instruction, 1, trace)?;
+                let sum = left_addend
+                    .checked_add(right_addend)
+                    .ok_or_else(|| self.overflow(&instruction))?;
+                self.write(&instruction, 2, sum, trace)?;
+            }
+            Opcode::Multiply => {
+                let multiplier = self.read(&instruction, 0, trace)?;
+                let multiplicand = self.read(&instruction, 1, trace)?;
+                let product = multiplier
+                    .checked_mul(multiplicand)
+                    .ok_or_else(|| self.overflow(&instruction))?;
+                self.write(&instruction, 2, product, trace)?;
+            }
+            Opcode::Input => match io.input()? {
+                Some(value) => self.write(&instruction, 0, value, trace)?,
+                None => return Ok(Status::Blocked),
+            },
+            Opcode::Output => io.output(self.read(&instruction, 0, trace)?)?,
+            Opcode::JumpIfTrue => {
+                if self.read(&instruction, 0, trace)? != 0 {
+                    next = self.jump(&instruction, trace)?;
+                }
+            }
+            Opcode::JumpIfFalse => {
+                if self.read(&instruction, 0, trace)? == 0 {
+                    next = self.jump(&instruction, trace)?;
+                }
+            }
+            Opcode::LessThan => {
+                let less =
+                    self.read(&instruction, 0, trace)? < self.read(&instruction, 1, trace)?;
+                self.write(&instruction, 2, less.into(), trace)?;
+            }
+            Opcode::Equals => {
+                let equal =
+                    self.read(&instruction, 0, trace)? == self.read(&instruction, 1, trace)?;
+                self.write(&instruction, 2, equal.into(), trace)?;
+            }
+            Opcode::AdjustRelativeBase => {
+                let offset = self.read(&instruction, 0, trace)?;
+                self.relative_base = self
+                    .relative_base
+                    .checked_add(offset)
+                    .ok_or_else(|| self.overflow(&instruction))?;
+            }
+            Opcode::Halt => {
+                self.executed += 1;
+                trace.executed()?;
+                return Ok(Status::Halted);
+            }
+        }
+        // Running off the end of memory never ends well, so stop at whatever
+        // sent the pointer there.
+        if next >= self.memory.len() {
+            return Err(Error::PointerOutOfBounds {
+                address: self.pointer,
+                opcode: instruction.opcode,
+                pointer: next as i64,
+            });
+        }
+        self.pointer = next;
+        self.executed += 1;
+        trace.executed()?;
+        if self.detect_loops {
+            self.detect_loop(&instruction)?;
+        }
+        Ok(Status::Running)
+    }
+
+    /// Execute instructions until the program halts or is blocked waiting
+    /// for input.
+    pub fn run(&mut self, io: &mut impl Io) -> Result<Status, Error> {
+        self.run_traced(io, &mut ())
+    }
+
+    /// Execute instructions until the program halts or is blocked waiting
+    /// for input, telling a tracer what each one reads and writes.
+    pub fn run_traced(
+        &mut self,
+        io: &mut impl Io,
+        trace: &mut impl Trace,
+    ) -> Result<Status, Error> {
+        loop {
+            match self.step_traced(io, trace)? {
+                Status::Running => continue,
+                status => return Ok(status),
+            }
+        }
+    }
+
+    /// The value at an address, which is zero if nothing has been written
+    /// there.
+    pub fn load(&self, address: i64) -> Result<i64, Error> {
+        let index = self.index(address)?;
+        Ok(self.memory.get(index).unwrap_or_default())
+    }
+
+    /// Store a value at an address, growing memory to fit if need be.
+    pub fn store(&mut self, address: i64, value: i64) -> Result<(), Error> {
+        let index = self.index(address)?;
+        self.memory.grow(index + 1);
+        self.memory.set(index, value);
+        Ok(())
+    }
+
+    #[inline]
+    fn index(&self, address: i64) -> Result<usize, Error> {
+        let index = usize::try_from(address).map_err(|_| Error::Address(address))?;
+        if index >= self.memory.len() && index >= self.limit {
+            return Err(Error::MemoryLimit {
+                address,
+                limit: self.limit,
+            });
+        }
+        Ok(index)
+    }
+
+    // The nth parameter of the current instruction, as it is in memory.
+    fn parameter(&self, instruction: &Instruction, n: usize) -> Result<i64, Error> {
+        let parameter = self.memory.get(self.pointer + 1 + n);
+        parameter.ok_or(Error::TruncatedInstruction {
+            address: self.pointer,
+            opcode: instruction.opcode,
+        })
+    }
+
+    // The address the nth parameter of the current instruction refers to,
+    // which must be somewhere memory can be.
+    fn address(&self, instruction: &Instruction, n: usize) -> Result<i64, Error> {
+        let parameter = self.parameter(instruction, n)?;
+        let address = match instruction.modes[n] {
+            Mode::Position => parameter,
+            Mode::Relative => self
+                .relative_base
+                .checked_add(parameter)
+                .ok_or_else(|| self.overflow(instruction))?,
+            Mode::Immediate => {
+                return Err(Error::ImmediateWrite {
+                    address: self.pointer,
+                    value: instruction.encode(),
+                })
+            }
+        };
+        match self.index(address) {
+            Ok(_) => Ok(address),
+            Err(_) => Err(Error::OperandOutOfBounds {
+                address: self.pointer,
+                opcode: instruction.opcode,
+                operand: address,
+            }),
+        }
+    }
+
+    // The value of the nth parameter of the current instruction.
+    fn read(
+        &self,
+        instruction: &Instruction,
+        n: usize,
+        trace: &mut impl Trace,
+    ) -> Result<i64, Error> {
+        let (address, value) = match instruction.modes[n] {
+            Mode::Immediate => (None, self.parameter(instruction, n)?),
+            _ => {
+                let address = self.address(instruction, n)?;
+                (Some(address), self.load(address)?)
+            }
+        };
+        trace.read(address, value);
+        Ok(value)
+    }
+
+    // Store a value at the address given by the nth parameter of the current
+    // instruction, which only makes sense in position or relative mode.
+    fn write(
+        &mut self,
+        instruction: &Instruction,
+        n: usize,
+        value: i64,
+        trace: &mut impl Trace,
+    ) -> Result<(), Error> {
+        let address = self.address(instruction, n)?;
+        self.store(address, value)?;
+        trace.write(address, value);
+        Ok(())
+    }
+
+    // Where a jump instruction is going.
+    fn jump(&self, instruction: &Instruction, trace: &mut impl Trace) -> Result<usize, Error> {
+        let target = self.read(instruction, 1, trace)?;
+        usize::try_from(target).map_err(|_| Error::PointerOutOfBounds {
+            address: self.pointer,
+            opcode: instruction.opcode,
+            pointer: target,
+        })
+    }
+
+    // Whether the computer is going round in a loop, having just executed an
+    // instruction. Every earlier state is forgotten whenever it reads input.
+    fn detect_loop(&mut self, instruction: &Instruction) -> Result<(), Error> {
+        if instruction.opcode == Opcode::Input {
+            self.loops = Loops::default();
+        }
+        let loops = &mut self.loops;
+        let Some((pointer, relative_base, memory)) = &loops.checkpoint else {
+            let state = (self.pointer, self.relative_base, self.memory.clone());
+            loops.origin = Some(state.clone());
+            loops.checkpoint = Some(state);
+            (loops.since, loops.power) = (0, 1);
+            return Ok(());
+        };
+
+        loops.since += 1;
+        let same = (*pointer, *relative_base) == (self.pointer, self.relative_base)
+            && *memory == self.memory;
+        if same {
+            let length = loops.since;
+            return Err(Error::Loop(self.loop_start(length)?));
+        }
+        if loops.since == loops.power {
+            loops.checkpoint = Some((self.pointer, self.relative_base, self.memory.clone()));
+            loops.since = 0;
+            loops.power *= 2;
+        }
+        Ok(())
+    }
+
+    // Where a loop of the given length starts, found by going from the last
+    // input again with two computers, one that far ahead of the other, until
+    // they're in the same state.
+    fn loop_start(&self, length: u64) -> Result<usize, Error> {
+        let Some((pointer, relative_base, memory)) = self.loops.origin.clone() else {
+            return Ok(self.pointer);
+        };
+        let mut behind = Vm {
+            memory,
+            pointer,
+            relative_base,
+            limit: self.limit,
+            executed: 0,
+            budget: None,
+            detect_loops: false,
+            loops: Loops::default(),
+        };
+        let mut ahead = behind.clone();
+        for _ in 0..length {
+            ahead.step(&mut ())?;
+        }
+        while (behind.pointer, behind.relative_base, &behind.memory)
+            != (ahead.pointer, ahead.relative_base, &ahead.memory)
+        {
+            behind.step(&mut ())?;
+            ahead.step(&mut ())?;
+        }
+        Ok(behind.pointer)
+    }
+
+    fn overflow(&self, instruction: &Instruction) -> Error {
+        Error::Overflow {
+            address: self.pointer,
+            opcode: instruction.opcode,
+        }
+    }
+}
+
+/// Run a program which needs no input until it halts, returning the final
+/// state of its memory.
+pub fn run(program: Vec<i64>) -> Result<Vec<i64>, Error> {
+    let mut vm = Vm::new(program).with_budget(BUDGET);
+    match vm.run(&mut ())? {
+        Status::Blocked => Err(Error::NoInput(vm.pointer())),
+        _ => Ok(vm.into_memory()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use proptest::{collection::vec, prelude::*};
+    use test_case::test_case;
+
+    use super::{io::Queue, run, Error, Instruction, Mode::*, Opcode, Opcode::*, Status, Vm};
+
+    #[test_case(1, Instruction { opcode: Add, modes: [Position, Position, Position] } ; "add")]
+    #[test_case(2, Instruction { opcode: Multiply, modes: [Position, Position, Position] } ; "multiply")]
+    #[test_case(99, Instruction { opcode: Halt, modes: [Position, Position, Position] } ; "halt")]
+    #[test_case(1002, Instruction { opcode: Multiply, modes: [Position, Immediate, Position] } ; "immediate")]
+    #[test_case(1101, Instruction { opcode: Add, modes: [Immediate, Immediate, Position] } ; "both immediate")]
+    #[test_case(3, Instruction { opcode: Input, modes: [Position, Position, Position] } ; "input")]
+    #[test_case(104, Instruction { opcode: Output, modes: [Immediate, Position, Position] } ; "output")]
+    #[test_case(1105, Instruction { opcode: JumpIfTrue, modes: [Immediate, Immediate, Position] } ; "jump if true")]
+    #[test_case(6, Instruction { opcode: JumpIfFalse, modes: [Position, Position, Position] } ; "jump if false")]
+    #[test_case(1107, Instruction { opcode: LessThan, modes: [Immediate, Immediate, Position] } ; "less than")]
+    #[test_case(8, Instruction { opcode: Equals, modes: [Position, Position, Position] } ; "equals")]
+    #[test_case(109, Instruction { opcode: AdjustRelativeBase, modes: [Immediate, Position, Position] } ; "adjust relative base")]
+    #[test_case(21201, Instruction { opcode: Add, modes: [Relative, Immediate, Relative] } ; "relative")]
+    fn decoding(value: i64, expected: Instruction) {
+        assert_eq!(Instruction::decode(0, value), Ok(expected));
+        assert_eq!(expected.encode(), value);
+    }
+
+    #[test_case(10, Error::Opcode { address: 7, value: 10 } ; "unknown opcode")]
+    #[test_case(-1, Error::Opcode { address: 7, value: -1 } ; "negative")]
+    #[test_case(301, Error::Mode { address: 7, value: 301 } ; "unknown mode")]
+    #[test_case(100001, Error::Mode { address: 7, value: 100001 } ; "too many modes")]
+    fn decoding_errors(value: i64, expected: Error) {
+        assert_eq!(Instruction::decode(7, value), Err(expected));
+    }
+
+    #[test_case(vec![1002, 4, 3, 4, 33], vec![1002, 4, 3, 4, 99] ; "multiply immediate")]
+    #[test_case(vec![1101, 100, 5, 0, 99], vec![105, 100, 5, 0, 99] ; "add immediate")]
+    fn running_with_modes(program: Vec<i64>, expected: Vec<i64>) -> anyhow::Result<()> {
+        assert_eq!(run(program)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn writing_in_immediate_mode() {
+        let program = vec![1, 0, 0, 0, 10001, 0, 0, 0, 99];
+        let expected = Error::ImmediateWrite {
+            address: 4,
+            value: 10001,
+        };
+        assert_eq!(run(program), Err(expected));
+    }
+
+    #[test]
+    fn echoing() -> anyhow::Result<()> {
+        let mut vm = Vm::new(vec![3, 0, 4, 0, 99]);
+        let mut io = Queue::new([42]);
+        assert_eq!(vm.run(&mut io)?, Status::Halted);
+        assert_eq!(io.output, vec![42]);
+        Ok(())
+    }
+
+    #[test]
+    fn pausing_for_input() -> anyhow::Result<()> {
+        // Add two inputs and output the sum.
+        let program = vec![3, 11, 3, 12, 1, 11, 12, 13, 4, 13, 99, 0, 0, 0];
+        let mut vm = Vm::new(program);
+        let mut io = Queue::new([2]);
+
+        assert_eq!(vm.run(&mut io)?, Status::Blocked);
+        assert_eq!(vm.pointer(), 2);
+        assert!(io.output.is_empty());
+
+        io.input.push_back(3);
+        assert_eq!(vm.run(&mut io)?, Status::Halted);
+        assert_eq!(io.output, vec![5]);
+        Ok(())
+    }
+
+    #[test]
+    fn running_without_input() {
+        assert_eq!(run(vec![3, 0, 99]), Err(Error::NoInput(0)));
+    }
+
+    // Compares its input to 8.
+    #[test_case(vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8], 8, 1 ; "equal to 8 by position")]
+    #[test_case(vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8], 7, 0 ; "not equal to 8 by position")]
+    #[test_case(vec![3, 9, 7, 9, 10, 9, 4, 9, 99, -1, 8], 7, 1 ; "less than 8 by position")]
+    #[test_case(vec![3, 9, 7, 9, 10, 9, 4, 9, 99, -1, 8], 8, 0 ; "not less than 8 by position")]
+    #[test_case(vec![3, 3, 1108, -1, 8, 3, 4, 3, 99], 8, 1 ; "equal to 8 immediately")]
+    #[test_case(vec![3, 3, 1107, -1, 8, 3, 4, 3, 99], 9, 0 ; "not less than 8 immediately")]
+    #[test_case(vec![3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9], 0, 0 ; "jump by position")]
+    #[test_case(vec![3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1], 5, 1 ; "jump immediately")]
+    fn comparing(program: Vec<i64>, input: i64, expected: i64) -> anyhow::Result<()> {
+        let mut io = Queue::new([input]);
+        Vm::new(program).run(&mut io)?;
+        assert_eq!(io.output, vec![expected]);
+        Ok(())
+    }
+
+    #[test_case(7, 999 ; "below 8")]
+    #[test_case(8, 1000 ; "equal to 8")]
+    #[test_case(9, 1001 ; "above 8")]
+    fn branching(input: i64, expected: i64) -> anyhow::Result<()> {
+        let program = vec![
+            3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0,
+            0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000, 1, 20, 4,
+            20, 1105, 1, 46, 98, 99,
+        ];
+        let mut io = Queue::new([input]);
+        Vm::new(program).run(&mut io)?;
+        assert_eq!(io.output, vec![expected]);
+        Ok(())
+    }
+
+    #[test]
+    fn jumping_to_itself() -> anyhow::Result<()> {
+        let mut vm = Vm::new(vec![1105, 1, 0]);
+        for _ in 0..3 {
+            assert_eq!(vm.step(&mut ())?, Status::Running);
+            assert_eq!(vm.pointer(), 0);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn jumping_into_an_instruction() -> anyhow::Result<()> {
+        // Jump past the 1101 at address 3 and into its parameters, which
+        // decode as an instruction to output 7.
+        let mut vm = Vm::new(vec![1105, 1, 5, 1101, 2, 104, 7, 99]);
+        let mut io = Queue::default();
+        assert_eq!(vm.run(&mut io)?, Status::Halted);
+        assert_eq!(io.output, vec![7]);
+        assert_eq!(vm.memory()[7], 99);
+        Ok(())
+    }
+
+    #[test]
+    fn overwriting_itself() -> anyhow::Result<()> {
+        // The first instruction overwrites its own opcode, but still moves on
+        // by its own width rather than that of what it wrote.
+        let mut vm = Vm::new(vec![1101, 3, 1, 0, 99]);
+        assert_eq!(vm.run(&mut ())?, Status::Halted);
+        assert_eq!(vm.memory().to_vec(), [4, 3, 1, 0, 99]);
+        Ok(())
+    }
+
+    #[test]
+    fn negative_numbers() -> anyhow::Result<()> {
+        assert_eq!(run(vec![1101, 100, -1, 4, 0])?, vec![1101, 100, -1, 4, 99]);
+        Ok(())
+    }
+
+    #[test_case(vec![1102, 34915192, 34915192, 7, 4, 7, 99, 0], vec![1219070632396864] ; "large product")]
+    #[test_case(vec![104, 1125899906842624, 99], vec![1125899906842624] ; "large output")]
+    #[test_case(
+        vec![109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99],
+        vec![109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99]
+        ; "quine"
+    )]
+    fn relative_base(program: Vec<i64>, expected: Vec<i64>) -> anyhow::Result<()> {
+        let mut io = Queue::default();
+        assert_eq!(Vm::new(program).run(&mut io)?, Status::Halted);
+        assert_eq!(io.output, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn growing_memory() -> anyhow::Result<()> {
+        let memory = run(vec![1101, 1, 1, 8, 4, 7, 99])?;
+        assert_eq!(memory, vec![1101, 1, 1, 8, 4, 7, 99, 0, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn reading_beyond_memory() -> anyhow::Result<()> {
+        let mut io = Queue::default();
+        Vm::new(vec![4, 1000, 99]).run(&mut io)?;
+        assert_eq!(io.output, vec![0]);
+        Ok(())
+    }
+
+    #[test_case(vec![1101, 1, 1, 100, 99], 0, Add, 100 ; "writing")]
+    #[test_case(vec![4, 100, 99], 0, Output, 100 ; "reading")]
+    #[test_case(vec![1101, 1, 1, -1, 99], 0, Add, -1 ; "negative address")]
+    #[test_case(vec![109, -5, 204, 1, 99], 2, Output, -4 ; "negative relative address")]
+    fn operands_out_of_bounds(program: Vec<i64>, address: usize, opcode: Opcode, operand: i64) {
+        let mut vm = Vm::new(program).with_memory_limit(10);
+        let expected = Error::OperandOutOfBounds {
+            address,
+            opcode,
+            operand,
+        };
+        assert_eq!(vm.run(&mut ()), Err(expected));
+    }
+
+    #[test_case(vec![1105, 1, -4], 0, JumpIfTrue, -4 ; "negative jump")]
+    #[test_case(vec![1105, 1, 3], 0, JumpIfTrue, 3 ; "jump past the end")]
+    #[test_case(vec![1101, 1, 1, 0], 0, Add, 4 ; "running off the end")]
+    fn pointers_out_of_bounds(program: Vec<i64>, address: usize, opcode: Opcode, pointer: i64) {
+        let expected = Error::PointerOutOfBounds {
+            address,
+            opcode,
+            pointer,
+        };
+        assert_eq!(run(program), Err(expected));
+    }
+
+    #[test_case(vec![1, 0, 0], 0, Add ; "missing a parameter")]
+    #[test_case(vec![1106, 0, 3, 4], 3, Output ; "at the end")]
+    fn truncated_instructions(program: Vec<i64>, address: usize, opcode: Opcode) {
+        let expected = Error::TruncatedInstruction { address, opcode };
+        assert_eq!(run(program), Err(expected));
+    }
+
+    #[test_case(vec![1101, i64::MAX, 1, 0, 99], Add ; "sum")]
+    #[test_case(vec![1102, i64::MIN, -1, 0, 99], Multiply ; "product")]
+    #[test_case(vec![109, i64::MAX, 109, 1, 99], AdjustRelativeBase ; "relative base")]
+    #[test_case(vec![109, i64::MAX, 204, 1, 99], Output ; "relative address")]
+    fn overflowing(program: Vec<i64>, opcode: Opcode) {
+        let address = if opcode == Add || opcode == Multiply {
+            0
+        } else {
+            2
+        };
+        assert_eq!(run(program), Err(Error::Overflow { address, opcode }));
+    }
+
+    #[test]
+    fn running_out_of_budget() -> anyhow::Result<()> {
+        // Count up forever, which never repeats a state.
+        let mut vm = Vm::new(vec![1001, 7, 1, 7, 1105, 1, 0, 0]).with_budget(7);
+        for _ in 0..7 {
+            assert_eq!(vm.step(&mut ())?, Status::Running);
+        }
+        let expected = Error::Budget {
+            address: 4,
+            executed: 7,
+        };
+        assert_eq!(vm.step(&mut ()), Err(expected));
+        assert_eq!(vm.memory()[7], 4);
+        Ok(())
+    }
+
+    #[test_case(vec![1105, 1, 0], 0 ; "jumping to itself")]
+    #[test_case(vec![1101, 0, 0, 9, 1106, 0, 4, 0, 0, 0], 4 ; "after a while")]
+    #[test_case(vec![1001, 14, 1, 14, 1007, 14, 3, 15, 1005, 15, 0, 1105, 1, 11, 0, 0], 11 ; "after counting")]
+    fn detecting_loops(program: Vec<i64>, start: usize) {
+        let mut vm = Vm::new(program).with_loop_detection();
+        assert_eq!(vm.run(&mut ()), Err(Error::Loop(start)));
+    }
+
+    #[test]
+    fn detecting_loops_with_input() -> anyhow::Result<()> {
+        // Echo each input, which is the same state over and over but for the
+        // input it reads each time around.
+        let mut vm = Vm::new(vec![3, 7, 4, 7, 1105, 1, 0, 0]).with_loop_detection();
+        let mut io = Queue::new([1, 1, 1]);
+        assert_eq!(vm.run(&mut io)?, Status::Blocked);
+        assert_eq!(io.output, vec![1, 1, 1]);
+        Ok(())
+    }
+
+    #[test_case(vec![1101, 0, 0, 0, 12], Error::Opcode { address: 4, value: 12 } ; "opcode")]
+    #[test_case(vec![1101, 0, 0, 0, 301], Error::Mode { address: 4, value: 301 } ; "mode")]
+    fn invalid_instructions(program: Vec<i64>, expected: Error) {
+        assert_eq!(run(program), Err(expected));
+    }
+
+    #[test]
+    fn empty_program() {
+        assert_eq!(run(vec![]), Err(Error::PointerPastEnd(0)));
+    }
+
+    // Mostly values which decode as instructions, with anything at all mixed
+    // in, so plenty of programs get somewhere before failing.
+    fn value() -> impl Strategy<Value = i64> {
+        prop_oneof![3 => -10..23000_i64, 1 => any::<i64>()]
+    }
+
+    proptest! {
+        #[test]
+        fn never_panicking(
+            program in vec(value(), 0..64),
+            input in vec(value(), 0..4),
+        ) {
+            let mut vm = Vm::new(program)
+                .with_memory_limit(1 << 12)
+                .with_budget(1000);
+            let _ = vm.run(&mut Queue::new(input));
+        }
+    }
+}
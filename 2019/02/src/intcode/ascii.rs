@@ -0,0 +1,233 @@
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead, Read, StdinLock, Stdout, Write},
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Error, Io, Vm};
+
+/// Text in and out as ASCII character codes. Each line read from the reader is
+/// input a character at a time, ending with a newline, and output is written
+/// as the characters it stands for. Output outside of the ASCII range, like a
+/// final answer, is written as a number on a line of its own.
+///
+/// Everything that goes in and out is recorded in a transcript of the session.
+pub struct Ascii<R, W> {
+    reader: R,
+    writer: W,
+    line: VecDeque<i64>,
+    transcript: Transcript,
+}
+
+impl<R: BufRead, W: Write> Ascii<R, W> {
+    pub fn new(reader: R, writer: W) -> Self {
+        Self {
+            reader,
+            writer,
+            line: VecDeque::new(),
+            transcript: Transcript::default(),
+        }
+    }
+
+    pub fn transcript(&self) -> &Transcript {
+        &self.transcript
+    }
+
+    pub fn into_transcript(self) -> Transcript {
+        self.transcript
+    }
+}
+
+impl Ascii<StdinLock<'static>, Stdout> {
+    /// Read lines typed at the terminal and write text back to it.
+    pub fn stdio() -> Self {
+        Self::new(io::stdin().lock(), io::stdout())
+    }
+}
+
+impl<R: BufRead, W: Write> Io for Ascii<R, W> {
+    fn input(&mut self) -> Result<Option<i64>, Error> {
+        if self.line.is_empty() {
+            let mut line = String::new();
+            let read = self
+                .reader
+                .read_line(&mut line)
+                .map_err(|error| Error::Io(error.to_string()))?;
+            if read == 0 {
+                return Ok(None);
+            }
+            let line = line.strip_suffix('\n').unwrap_or(&line);
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            if !line.is_ascii() {
+                return Err(Error::Io(format!("Input isn't ASCII: {line:?}")));
+            }
+            self.line = line.bytes().map(i64::from).chain([10]).collect();
+            self.transcript.events.push(Event::Input(line.to_string()));
+        }
+        Ok(self.line.pop_front())
+    }
+
+    fn output(&mut self, value: i64) -> Result<(), Error> {
+        let text = match u8::try_from(value) {
+            Ok(byte) if byte.is_ascii() => char::from(byte).to_string(),
+            _ => format!("{value}\n"),
+        };
+        match self.transcript.events.last_mut() {
+            Some(Event::Output(output)) => output.push_str(&text),
+            _ => self.transcript.events.push(Event::Output(text.clone())),
+        }
+        self.writer
+            .write_all(text.as_bytes())
+            .and_then(|()| self.writer.flush())
+            .map_err(|error| Error::Io(error.to_string()))
+    }
+}
+
+/// Something that happened in an ASCII session.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Event {
+    /// A line of input, without its newline.
+    Input(String),
+    /// Text output between lines of input.
+    Output(String),
+}
+
+/// Everything that went in and out during an ASCII session, in order.
+///
+/// A transcript is saved as a line of JSON per event, and can be replayed by
+/// feeding its input to a computer again, which should give the same
+/// transcript if the program behaves the same way.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Transcript {
+    pub events: Vec<Event>,
+}
+
+impl Transcript {
+    /// The lines which were input, as they'd be typed.
+    pub fn script(&self) -> String {
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Input(line) => Some(format!("{line}\n")),
+                Event::Output(_) => None,
+            })
+            .collect()
+    }
+
+    /// Run a computer with the same input as this session, writing its
+    /// output to a writer, and give the transcript of the new session.
+    pub fn replay(&self, vm: &mut Vm, writer: impl Write) -> Result<Transcript, Error> {
+        let script = self.script();
+        let mut io = Ascii::new(script.as_bytes(), writer);
+        vm.run(&mut io)?;
+        Ok(io.into_transcript())
+    }
+
+    pub fn save(&self, mut writer: impl Write) -> io::Result<()> {
+        for event in &self.events {
+            serde_json::to_writer(&mut writer, event)?;
+            writeln!(writer)?;
+        }
+        writer.flush()
+    }
+
+    pub fn load(reader: impl Read) -> io::Result<Self> {
+        let events = io::BufReader::new(reader)
+            .lines()
+            .filter(|line| !matches!(line, Ok(line) if line.trim().is_empty()))
+            .map(|line| Ok(serde_json::from_str(&line?)?))
+            .collect::<io::Result<_>>()?;
+        Ok(Self { events })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::ErrorKind;
+
+    use pretty_assertions::assert_eq;
+
+    use super::{Ascii, Event, Transcript};
+    use crate::intcode::{asm, Error, Status, Vm};
+
+    // Echo each line typed at a prompt, followed by its length in thousands.
+    const ECHO: &str = "
+        prompt: OUT 63
+                OUT 10
+                ADD 0 0 -> [n]
+        read:   IN -> [c]
+                EQ [c] 10 -> [t]
+                JT [t] done
+                OUT [c]
+                ADD [n] 1000 -> [n]
+                JT 1 read
+        done:   OUT 10
+                OUT [n]
+                JT 1 prompt
+        c:      DATA 0
+        t:      DATA 0
+        n:      DATA 0
+    ";
+
+    fn echo() -> anyhow::Result<Vm> {
+        Ok(Vm::new(asm::assemble(ECHO)?))
+    }
+
+    fn session() -> Transcript {
+        Transcript {
+            events: vec![
+                Event::Output("?\n".into()),
+                Event::Input("hi".into()),
+                Event::Output("hi\n2000\n?\n".into()),
+                Event::Input("you".into()),
+                Event::Output("you\n3000\n?\n".into()),
+            ],
+        }
+    }
+
+    #[test]
+    fn talking() -> anyhow::Result<()> {
+        let mut output = vec![];
+        let mut io = Ascii::new("hi\r\nyou".as_bytes(), &mut output);
+        assert_eq!(echo()?.run(&mut io)?, Status::Blocked);
+        assert_eq!(io.into_transcript(), session());
+        assert_eq!(String::from_utf8(output)?, "?\nhi\n2000\n?\nyou\n3000\n?\n");
+        Ok(())
+    }
+
+    #[test]
+    fn replaying() -> anyhow::Result<()> {
+        assert_eq!(session().replay(&mut echo()?, vec![])?, session());
+
+        // A program which answers differently gives a different transcript.
+        let mut changed = echo()?;
+        changed.store(21, 100)?;
+        assert_ne!(session().replay(&mut changed, vec![])?, session());
+        Ok(())
+    }
+
+    #[test]
+    fn saving() -> anyhow::Result<()> {
+        let mut saved = vec![];
+        session().save(&mut saved)?;
+        assert!(String::from_utf8(saved.clone())?.starts_with("{\"output\":\"?\\n\"}\n"));
+        assert_eq!(Transcript::load(saved.as_slice())?, session());
+        Ok(())
+    }
+
+    #[test]
+    fn loading_garbage() {
+        let error = Transcript::load("{\"typed\":\"hi\"}".as_bytes()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn not_ascii() -> anyhow::Result<()> {
+        let mut io = Ascii::new("héllo\n".as_bytes(), vec![]);
+        let error = echo()?.run(&mut io).unwrap_err();
+        assert_eq!(error, Error::Io("Input isn't ASCII: \"héllo\"".into()));
+        Ok(())
+    }
+}
@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+
+use aoc_core::parse::{self, ParseError};
+
+use super::{Instruction, Mode, Opcode};
+
+/// Why a line of assembly couldn't be assembled.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum Error {
+    #[error("Unknown mnemonic")]
+    Mnemonic,
+    #[error("{mnemonic} takes {expected} operand(s)")]
+    Operands {
+        mnemonic: &'static str,
+        expected: usize,
+    },
+    #[error("Expected a number, a label, [address] or [rb+offset]")]
+    Operand,
+    #[error("Invalid label")]
+    Label,
+    #[error("Undefined label")]
+    UndefinedLabel,
+    #[error("Label is already defined")]
+    DuplicateLabel,
+    #[error("Expected address {0}")]
+    Address(usize),
+    #[error("Can't write to an immediate operand")]
+    ImmediateWrite,
+    #[error("Label plus offset is out of range")]
+    Overflow,
+}
+
+// A line which assembles to something, before its operands are resolved.
+struct Statement<'a> {
+    number: usize,
+    text: &'a str,
+    opcode: Option<Opcode>,
+    operands: Vec<&'a str>,
+}
+
+/// Assemble a program written with the mnemonics of a disassembly listing.
+///
+/// ```text
+/// ; Multiply the sum of two numbers by a third.
+///       ADD [x] [y] -> [3]
+/// 0004: MUL [3] [z] -> [0]
+///       HALT
+/// x:    DATA 30
+/// y:    DATA 40
+/// z:    DATA 50
+/// ```
+///
+/// Operands are `[address]` in position mode, plain numbers in immediate mode
+/// and `[rb+offset]` in relative mode. A label stands for the address it's
+/// defined at, optionally with an offset like `label+1`, and the `->` marking
+/// where an instruction writes is optional. A line may start with the address
+/// it should be at, like a listing does, which is checked. Everything after a
+/// `;` is a comment, so a listing assembles back into the program it came from.
+pub fn assemble(source: &str) -> Result<Vec<i64>, ParseError> {
+    let mut labels = HashMap::new();
+    let mut statements = vec![];
+    let mut address = 0;
+
+    for (number, text) in source.lines().enumerate() {
+        let number = number + 1;
+        let error =
+            |reason: Error, offending| ParseError::new(reason, text, offending).on_line(number);
+
+        let code = text.split(';').next().unwrap_or_default();
+        let mut tokens = code
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|token| !token.is_empty() && *token != "->")
+            .peekable();
+
+        while let Some(prefix) = tokens.next_if(|token| token.ends_with(':')) {
+            let name = &prefix[..prefix.len() - 1];
+            if name.bytes().all(|b| b.is_ascii_digit()) {
+                if name.parse() != Ok(address) {
+                    return Err(error(Error::Address(address), name));
+                }
+            } else if !is_label(name) {
+                return Err(error(Error::Label, name));
+            } else if labels.insert(name, address).is_some() {
+                return Err(error(Error::DuplicateLabel, name));
+            }
+        }
+
+        let Some(mnemonic) = tokens.next() else {
+            continue;
+        };
+        let operands: Vec<_> = tokens.collect();
+        let opcode = if mnemonic.eq_ignore_ascii_case("DATA") {
+            None
+        } else {
+            let opcode = Opcode::ALL
+                .into_iter()
+                .find(|opcode| mnemonic.eq_ignore_ascii_case(opcode.mnemonic()))
+                .ok_or_else(|| error(Error::Mnemonic, mnemonic))?;
+            if operands.len() != opcode.parameters() {
+                let reason = Error::Operands {
+                    mnemonic: opcode.mnemonic(),
+                    expected: opcode.parameters(),
+                };
+                return Err(error(reason, code.trim()));
+            }
+            Some(opcode)
+        };
+
+        address += usize::from(opcode.is_some()) + operands.len();
+        statements.push(Statement {
+            number,
+            text,
+            opcode,
+            operands,
+        });
+    }
+
+    let mut program = Vec::with_capacity(address);
+    for statement in statements {
+        let error = |reason: Error, offending| {
+            ParseError::new(reason, statement.text, offending).on_line(statement.number)
+        };
+        let operand = |token| {
+            operand(&labels, statement.text, token).map_err(|e| e.on_line(statement.number))
+        };
+
+        let Some(opcode) = statement.opcode else {
+            for token in statement.operands {
+                program.push(match operand(token)? {
+                    (value, Mode::Immediate) => value,
+                    _ => return Err(error(Error::Operand, token)),
+                });
+            }
+            continue;
+        };
+
+        let mut instruction = Instruction {
+            opcode,
+            modes: [Mode::Position; 3],
+        };
+        let mut parameters = vec![];
+        for (n, &token) in statement.operands.iter().enumerate() {
+            let (parameter, mode) = operand(token)?;
+            if mode == Mode::Immediate && opcode.writes() == Some(n) {
+                return Err(error(Error::ImmediateWrite, token));
+            }
+            instruction.modes[n] = mode;
+            parameters.push(parameter);
+        }
+        program.push(instruction.encode());
+        program.extend(parameters);
+    }
+    Ok(program)
+}
+
+fn is_label(name: &str) -> bool {
+    let mut chars = name.chars();
+    let starts = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+    starts && chars.all(|c| c.is_ascii_alphanumeric() || c == '_') && name != "rb"
+}
+
+// An operand's parameter and mode.
+fn operand(
+    labels: &HashMap<&str, usize>,
+    text: &str,
+    token: &str,
+) -> Result<(i64, Mode), ParseError> {
+    let Some(inner) = token.strip_prefix('[').and_then(|t| t.strip_suffix(']')) else {
+        return Ok((value(labels, text, token)?, Mode::Immediate));
+    };
+    match inner.strip_prefix("rb") {
+        Some("") => Ok((0, Mode::Relative)),
+        Some(offset) if offset.starts_with(['+', '-']) => {
+            Ok((parse::value(text, offset)?, Mode::Relative))
+        }
+        _ => Ok((value(labels, text, inner)?, Mode::Position)),
+    }
+}
+
+// A number, or a label with an optional offset like `label+1`.
+fn value(labels: &HashMap<&str, usize>, text: &str, token: &str) -> Result<i64, ParseError> {
+    if let Ok(value) = token.parse() {
+        return Ok(value);
+    }
+
+    let (label, offset) = match token.find(['+', '-']) {
+        Some(sign) => (&token[..sign], parse::value(text, &token[sign..])?),
+        None => (token, 0),
+    };
+    if !is_label(label) {
+        return Err(ParseError::new(Error::Operand, text, token));
+    }
+    match labels.get(label) {
+        Some(&address) => (address as i64)
+            .checked_add(offset)
+            .ok_or_else(|| ParseError::new(Error::Overflow, text, token)),
+        None => Err(ParseError::new(Error::UndefinedLabel, text, label)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use super::{assemble, Error};
+    use crate::intcode::disasm::disassemble;
+
+    #[test_case("ADD [9] [10] -> [3]", vec![1, 9, 10, 3] ; "positions")]
+    #[test_case("mul [4] 3 [4]", vec![1002, 4, 3, 4] ; "immediate")]
+    #[test_case("ARB -1\nOUT [rb+1]\nADD 2 3 -> [rb]", vec![109, -1, 204, 1, 21101, 2, 3, 0] ; "relative")]
+    #[test_case("IN -> [rb-2]", vec![203, -2] ; "negative offset")]
+    #[test_case("DATA 1, 2, -3", vec![1, 2, -3] ; "data")]
+    #[test_case("; Nothing to see here\n\n   HALT ; Stop", vec![99] ; "comments")]
+    fn assembling(source: &str, expected: Vec<i64>) -> anyhow::Result<()> {
+        assert_eq!(assemble(source)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn labels() -> anyhow::Result<()> {
+        let source = "\
+start:  IN -> [count]
+loop:   OUT [count]
+        ADD [count] -1 -> [count]
+        JT [count] loop
+        JF 0 end
+count:  DATA 0
+end:    HALT
+        DATA start count+1 loop-2
+";
+        let expected = vec![
+            3, 14, 4, 14, 1001, 14, -1, 14, 1005, 14, 2, 1106, 0, 15, 0, 99, 0, 15, 0,
+        ];
+        assert_eq!(assemble(source)?, expected);
+        Ok(())
+    }
+
+    #[test_case("FOO [1]", 1, 0..3, Error::Mnemonic ; "unknown mnemonic")]
+    #[test_case("ADD [1] [2]", 1, 0..11, Error::Operands { mnemonic: "ADD", expected: 3 } ; "too few operands")]
+    #[test_case("HALT\nOUT [x]", 2, 5..6, Error::UndefinedLabel ; "undefined label")]
+    #[test_case("x: HALT\nx: HALT", 2, 0..1, Error::DuplicateLabel ; "duplicate label")]
+    #[test_case("1x: HALT", 1, 0..2, Error::Label ; "invalid label")]
+    #[test_case("HALT\n0002: HALT", 2, 0..4, Error::Address(1) ; "wrong address")]
+    #[test_case("ADD 1 2 3", 1, 8..9, Error::ImmediateWrite ; "immediate write")]
+    #[test_case("OUT [1", 1, 4..6, Error::Operand ; "unclosed bracket")]
+    #[test_case("DATA [1]", 1, 5..8, Error::Operand ; "data in brackets")]
+    #[test_case("DATA 0\nx: DATA x+9223372036854775807", 2, 8..29, Error::Overflow ; "offset overflow")]
+    fn errors(source: &str, line: usize, columns: std::ops::Range<usize>, reason: Error) {
+        let error = assemble(source).unwrap_err();
+        assert_eq!((error.line, error.columns.clone()), (line, columns));
+        assert_eq!(error.reason_is(), Some(&reason));
+    }
+
+    #[test_case(&[1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50] ; "example")]
+    #[test_case(&[109, -1, 204, 1, 21101, 2, 3, 0, 3, 0, 99] ; "relative")]
+    #[test_case(&[1006, 13, 3, 1101, 2, 104, 7, 1105, 1, 5, 0, 0, 0, 0] ; "overlapping")]
+    #[test_case(&[
+        3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0, 0,
+        1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000, 1, 20, 4, 20,
+        1105, 1, 46, 98, 99,
+    ] ; "jumps")]
+    fn round_trip(program: &[i64]) -> anyhow::Result<()> {
+        let listing = disassemble(program).to_string();
+        assert_eq!(assemble(&listing)?, program);
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_input() -> anyhow::Result<()> {
+        let program = crate::parse(include_str!("../../input.txt"))?;
+        let listing = disassemble(&program).to_string();
+        assert_eq!(assemble(&listing)?, program);
+        Ok(())
+    }
+}
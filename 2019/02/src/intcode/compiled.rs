@@ -0,0 +1,453 @@
+use std::sync::Arc;
+
+use super::{disasm, Error, Instruction, Io, Memory, Mode, Opcode, Status, Vm};
+
+/// A parameter of a decoded instruction, along with what its mode makes of it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operand {
+    /// A position which was already in memory, so can't be out of bounds.
+    Address(usize),
+    Position(i64),
+    Immediate(i64),
+    Relative(i64),
+}
+
+/// An instruction decoded along with its parameters, so executing it again
+/// only has to look them up.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Decoded {
+    instruction: Instruction,
+    operands: [Operand; 3],
+}
+
+/// What a program's code decoded as up front, shared between clones.
+#[derive(Debug, PartialEq)]
+struct Program {
+    /// The instruction at each address which is found to be code.
+    decoded: Vec<Option<Decoded>>,
+    /// Which of those instructions each address is part of, as a bit for
+    /// each of the addresses up to three before it which they start at.
+    covers: Vec<u8>,
+}
+
+/// A computer which decodes its program's instructions once up front, rather
+/// than every time it executes one like [`Vm`] does. That makes loops, and
+/// running the same program over and over, faster, since clones share what
+/// was decoded up front. Cloning into the same copy each time with
+/// [`Clone::clone_from`] doesn't allocate at all.
+///
+/// Writing over a decoded instruction forgets it, so it's decoded again the
+/// next time it's executed and self-modifying programs behave exactly as they
+/// do on a [`Vm`]. Looking for loops compares memory after every
+/// instruction anyway, so computers doing that are simply run by the [`Vm`].
+#[derive(Debug, PartialEq)]
+pub struct Compiled {
+    vm: Vm,
+    program: Arc<Program>,
+    /// Which instructions of the program have been written over since.
+    stale: Vec<bool>,
+    /// Instructions decoded while running, because they weren't found to be
+    /// code up front or have been written over since.
+    redecoded: Vec<Option<Decoded>>,
+}
+
+impl Clone for Compiled {
+    fn clone(&self) -> Self {
+        Self {
+            vm: self.vm.clone(),
+            program: Arc::clone(&self.program),
+            stale: self.stale.clone(),
+            redecoded: self.redecoded.clone(),
+        }
+    }
+
+    /// Copy another computer into this one, reusing what it has allocated
+    /// rather than allocating it all again. Running a program over and over
+    /// is quickest by cloning from the same computer into another each time.
+    fn clone_from(&mut self, source: &Self) {
+        self.vm.clone_from(&source.vm);
+        self.program.clone_from(&source.program);
+        self.stale.clone_from(&source.stale);
+        self.redecoded.clone_from(&source.redecoded);
+    }
+}
+
+impl Compiled {
+    pub fn new(vm: Vm) -> Self {
+        let memory = &vm.memory;
+        let mut decoded = vec![None; memory.len()];
+        let mut covers = vec![0; memory.len()];
+        for (address, instruction) in disasm::code(&memory.to_vec()) {
+            decoded[address] = decode(memory, address).ok();
+            for offset in 0..instruction.opcode.width() {
+                covers[address + offset] |= 1 << offset;
+            }
+        }
+        Self {
+            stale: vec![false; memory.len()],
+            program: Arc::new(Program { decoded, covers }),
+            redecoded: vec![],
+            vm,
+        }
+    }
+
+    pub fn vm(&self) -> &Vm {
+        &self.vm
+    }
+
+    pub fn into_vm(self) -> Vm {
+        self.vm
+    }
+
+    /// Store a value at an address, forgetting any instruction there.
+    pub fn store(&mut self, address: i64, value: i64) -> Result<(), Error> {
+        self.vm.store(address, value)?;
+        self.forget(address as usize);
+        Ok(())
+    }
+
+    /// Execute instructions until the program halts or is blocked waiting
+    /// for input.
+    pub fn run(&mut self, io: &mut impl Io) -> Result<Status, Error> {
+        if self.vm.detect_loops {
+            self.stale.fill(true);
+            self.redecoded.clear();
+            return self.vm.run(io);
+        }
+        loop {
+            match self.step(io)? {
+                Status::Running => continue,
+                status => return Ok(status),
+            }
+        }
+    }
+
+    // Execute a single instruction, exactly as `Vm::step` would. This is
+    // generic, so it's compiled in whichever crate runs a program, which is
+    // why the helpers it calls for every instruction are marked inline.
+    fn step(&mut self, io: &mut impl Io) -> Result<Status, Error> {
+        let pointer = self.vm.pointer;
+        let cached = match self.stale.get(pointer) {
+            Some(false) => self.program.decoded[pointer],
+            _ => self.redecoded.get(pointer).copied().flatten(),
+        };
+        let decoded = match cached {
+            Some(decoded) => decoded,
+            None => self.decode()?,
+        };
+        if self
+            .vm
+            .budget
+            .is_some_and(|budget| self.vm.executed >= budget)
+        {
+            return Err(Error::Budget {
+                address: pointer,
+                executed: self.vm.executed,
+            });
+        }
+        let opcode = decoded.instruction.opcode;
+
+        let mut next = pointer + opcode.width();
+        match opcode {
+            Opcode::Add => {
+                let left_addend = self.read(&decoded, 0)?;
+                let right_addend = self.read(&decoded, 1)?;
+                let sum = left_addend
+                    .checked_add(right_addend)
+                    .ok_or_else(|| self.vm.overflow(&decoded.instruction))?;
+                self.write(&decoded, 2, sum)?;
+            }
+            Opcode::Multiply => {
+                let multiplier = self.read(&decoded, 0)?;
+                let multiplicand = self.read(&decoded, 1)?;
+                let product = multiplier
+                    .checked_mul(multiplicand)
+                    .ok_or_else(|| self.vm.overflow(&decoded.instruction))?;
+                self.write(&decoded, 2, product)?;
+            }
+            Opcode::Input => match io.input()? {
+                Some(value) => self.write(&decoded, 0, value)?,
+                None => return Ok(Status::Blocked),
+            },
+            Opcode::Output => io.output(self.read(&decoded, 0)?)?,
+            Opcode::JumpIfTrue => {
+                if self.read(&decoded, 0)? != 0 {
+                    next = self.jump(&decoded)?;
+                }
+            }
+            Opcode::JumpIfFalse => {
+                if self.read(&decoded, 0)? == 0 {
+                    next = self.jump(&decoded)?;
+                }
+            }
+            Opcode::LessThan => {
+                let less = self.read(&decoded, 0)? < self.read(&decoded, 1)?;
+                self.write(&decoded, 2, less.into())?;
+            }
+            Opcode::Equals => {
+                let equal = self.read(&decoded, 0)? == self.read(&decoded, 1)?;
+                self.write(&decoded, 2, equal.into())?;
+            }
+            Opcode::AdjustRelativeBase => {
+                let offset = self.read(&decoded, 0)?;
+                self.vm.relative_base = self
+                    .vm
+                    .relative_base
+                    .checked_add(offset)
+                    .ok_or_else(|| self.vm.overflow(&decoded.instruction))?;
+            }
+            Opcode::Halt => {
+                self.vm.executed += 1;
+                return Ok(Status::Halted);
+            }
+        }
+        if next >= self.vm.memory.len() {
+            return Err(Error::PointerOutOfBounds {
+                address: pointer,
+                opcode,
+                pointer: next as i64,
+            });
+        }
+        self.vm.pointer = next;
+        self.vm.executed += 1;
+        Ok(Status::Running)
+    }
+
+    // Decode the current instruction, which hasn't been yet or has been
+    // written over since.
+    fn decode(&mut self) -> Result<Decoded, Error> {
+        let pointer = self.vm.pointer;
+        if pointer >= self.vm.memory.len() {
+            return Err(Error::PointerPastEnd(pointer));
+        }
+        let decoded = decode(&self.vm.memory, pointer)?;
+        if let Some(stale) = self.stale.get_mut(pointer) {
+            *stale = true;
+        }
+        if pointer >= self.redecoded.len() {
+            self.redecoded.resize(pointer + 1, None);
+        }
+        self.redecoded[pointer] = Some(decoded);
+        Ok(decoded)
+    }
+
+    // Forget every decoded instruction which covers an address. Most writes
+    // are to data, which isn't part of any.
+    #[inline]
+    fn forget(&mut self, address: usize) {
+        // Each bit is an instruction starting that far before the address.
+        let mut covers = self.program.covers.get(address).copied().unwrap_or(0);
+        while covers != 0 {
+            self.stale[address - covers.trailing_zeros() as usize] = true;
+            covers &= covers - 1;
+        }
+        let starts = address.saturating_sub(3)..self.redecoded.len().min(address + 1);
+        for start in starts {
+            let entry = &mut self.redecoded[start];
+            if entry.is_some_and(|d| start + d.instruction.opcode.width() > address) {
+                *entry = None;
+            }
+        }
+    }
+
+    // The address the nth operand of an instruction refers to.
+    #[inline]
+    fn address(&self, decoded: &Decoded, n: usize) -> Result<usize, Error> {
+        let address = match decoded.operands[n] {
+            Operand::Address(index) => return Ok(index),
+            Operand::Position(address) => address,
+            Operand::Relative(offset) => self
+                .vm
+                .relative_base
+                .checked_add(offset)
+                .ok_or_else(|| self.vm.overflow(&decoded.instruction))?,
+            Operand::Immediate(_) => {
+                return Err(Error::ImmediateWrite {
+                    address: self.vm.pointer,
+                    value: decoded.instruction.encode(),
+                })
+            }
+        };
+        self.vm
+            .index(address)
+            .map_err(|_| Error::OperandOutOfBounds {
+                address: self.vm.pointer,
+                opcode: decoded.instruction.opcode,
+                operand: address,
+            })
+    }
+
+    // The value of the nth operand of an instruction.
+    #[inline]
+    fn read(&self, decoded: &Decoded, n: usize) -> Result<i64, Error> {
+        match decoded.operands[n] {
+            Operand::Immediate(value) => Ok(value),
+            Operand::Address(index) => Ok(self.vm.memory[index]),
+            _ => {
+                let index = self.address(decoded, n)?;
+                Ok(self.vm.memory.get(index).unwrap_or_default())
+            }
+        }
+    }
+
+    // Store a value at the address the nth operand of an instruction refers
+    // to, forgetting any instruction there.
+    #[inline]
+    fn write(&mut self, decoded: &Decoded, n: usize, value: i64) -> Result<(), Error> {
+        let index = self.address(decoded, n)?;
+        self.vm.memory.grow(index + 1);
+        self.vm.memory.set(index, value);
+        self.forget(index);
+        Ok(())
+    }
+
+    // Where a jump instruction is going.
+    fn jump(&self, decoded: &Decoded) -> Result<usize, Error> {
+        let target = self.read(decoded, 1)?;
+        usize::try_from(target).map_err(|_| Error::PointerOutOfBounds {
+            address: self.vm.pointer,
+            opcode: decoded.instruction.opcode,
+            pointer: target,
+        })
+    }
+}
+
+// Decode the instruction at an address, as long as its parameters are all in
+// memory.
+fn decode(memory: &Memory, address: usize) -> Result<Decoded, Error> {
+    let instruction = Instruction::decode(address, memory[address])?;
+    if address + instruction.opcode.width() > memory.len() {
+        return Err(Error::TruncatedInstruction {
+            address,
+            opcode: instruction.opcode,
+        });
+    }
+
+    let mut operands = [Operand::Immediate(0); 3];
+    let parameters = (address + 1..).map(|address| memory[address]);
+    for ((operand, mode), parameter) in operands
+        .iter_mut()
+        .zip(instruction.modes)
+        .zip(parameters)
+        .take(instruction.opcode.parameters())
+    {
+        *operand = match mode {
+            Mode::Position => match usize::try_from(parameter) {
+                Ok(index) if index < memory.len() => Operand::Address(index),
+                _ => Operand::Position(parameter),
+            },
+            Mode::Immediate => Operand::Immediate(parameter),
+            Mode::Relative => Operand::Relative(parameter),
+        };
+    }
+    Ok(Decoded {
+        instruction,
+        operands,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use proptest::{collection::vec, prelude::*};
+    use test_case::test_case;
+
+    use super::Compiled;
+    use crate::intcode::{asm, io::Queue, Error, Status, Vm};
+
+    // Run a program on both engines, which should end up exactly the same.
+    fn both(vm: Vm, input: &[i64]) -> (Result<Status, Error>, Vm, Vec<i64>) {
+        let mut interpreted = vm.clone();
+        let mut interpreted_io = Queue::new(input.iter().copied());
+        let interpreted_status = interpreted.run(&mut interpreted_io);
+
+        let mut compiled = Compiled::new(vm);
+        let mut compiled_io = Queue::new(input.iter().copied());
+        let compiled_status = compiled.run(&mut compiled_io);
+
+        assert_eq!(compiled_status, interpreted_status);
+        assert_eq!(compiled.vm(), &interpreted);
+        assert_eq!(compiled_io, interpreted_io);
+        (compiled_status, compiled.into_vm(), compiled_io.output)
+    }
+
+    #[test_case(vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50], &[] ; "example")]
+    #[test_case(vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8], &[8] ; "comparing")]
+    #[test_case(vec![109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99], &[] ; "quine")]
+    #[test_case(vec![1101, 100, -1, 4, 0], &[] ; "modifying the next instruction")]
+    #[test_case(vec![1101, 1, 1, 7, 1101, 5, 5, 0, 99], &[] ; "overwriting a decoded instruction")]
+    #[test_case(vec![1002, 4, 3, 4, 33], &[] ; "overwriting its own parameter")]
+    fn matching_the_interpreter(program: Vec<i64>, input: &[i64]) {
+        let _ = both(Vm::new(program), input);
+    }
+
+    #[test]
+    fn self_modifying() -> anyhow::Result<()> {
+        // The first instruction is decoded and executed, and then turned into
+        // a halt once the loop after it is done.
+        let program = asm::assemble(
+            "
+            start:  JT 1 loop
+            loop:   ADD [n] -1 -> [n]
+                    JT [n] loop
+                    ADD [start] -1006 -> [start]
+                    JT 1 start
+            n:      DATA 3
+            ",
+        )?;
+        let (status, vm, _) = both(Vm::new(program).with_budget(100), &[]);
+        assert_eq!(status, Ok(Status::Halted));
+        assert_eq!(vm.memory()[0], 99);
+        assert_eq!(vm.executed(), 10);
+        Ok(())
+    }
+
+    #[test]
+    fn storing() -> anyhow::Result<()> {
+        let mut compiled = Compiled::new(Vm::new(vec![1101, 1, 1, 0, 99]));
+        compiled.store(2, 41)?;
+        assert_eq!(compiled.run(&mut ())?, Status::Halted);
+        assert_eq!(compiled.vm().memory()[0], 42);
+        Ok(())
+    }
+
+    #[test]
+    fn cloning_into() -> anyhow::Result<()> {
+        // Each run writes over the first instruction's parameter, which the
+        // next mustn't still see.
+        let compiled = Compiled::new(Vm::new(vec![1101, 0, 1, 0, 99]));
+        let mut run = compiled.clone();
+        for value in [1, 2, 3] {
+            run.clone_from(&compiled);
+            run.store(1, value)?;
+            assert_eq!(run.run(&mut ())?, Status::Halted);
+            assert_eq!(run.vm().memory()[0], value + 1);
+        }
+        run.clone_from(&compiled);
+        assert_eq!(run, compiled);
+        Ok(())
+    }
+
+    #[test]
+    fn detecting_loops() {
+        let vm = Vm::new(vec![1105, 1, 0]).with_loop_detection();
+        let (status, _, _) = both(vm, &[]);
+        assert_eq!(status, Err(Error::Loop(0)));
+    }
+
+    // Mostly values which decode as instructions or are small enough to be
+    // addresses, so plenty of programs modify themselves before failing.
+    fn value() -> impl Strategy<Value = i64> {
+        prop_oneof![3 => -10..23000_i64, 3 => 0..64_i64, 1 => any::<i64>()]
+    }
+
+    proptest! {
+        #[test]
+        fn agreeing(program in vec(value(), 0..64), input in vec(value(), 0..4)) {
+            let vm = Vm::new(program)
+                .with_memory_limit(1 << 12)
+                .with_budget(1000);
+            let _ = both(vm, &input);
+        }
+    }
+}
@@ -0,0 +1,372 @@
+use std::{
+    collections::BTreeSet,
+    io::{self, BufRead, Write},
+};
+
+use super::{disasm, io::Queue, Error, Status, Vm};
+
+const HELP: &str = "\
+step [n]              Execute the next n instructions, or just the next one
+continue              Execute instructions until a breakpoint or the program stops
+break [address]       Set a breakpoint, or list them
+delete <address>      Delete a breakpoint
+where                 Show the current instruction and relative base
+memory <address> [n]  Show n values from an address, or just one
+set <address> <value>...
+                      Patch memory, starting from an address
+input <value>...      Queue input for the program
+help                  Show this help
+quit                  Stop debugging";
+
+// The most values `memory` shows at once.
+const MEMORY_VALUES: usize = 1000;
+
+/// An interactive debugger for an Intcode program, driven by commands like
+/// `step`, `break 10` and `memory 9 3`. Input for the program is queued with
+/// `input` and its output is shown as it happens.
+pub struct Debugger {
+    vm: Vm,
+    io: Queue,
+    breakpoints: BTreeSet<usize>,
+    status: Status,
+}
+
+impl Debugger {
+    pub fn new(vm: Vm) -> Self {
+        Self {
+            vm,
+            io: Queue::default(),
+            breakpoints: BTreeSet::new(),
+            status: Status::Running,
+        }
+    }
+
+    pub fn vm(&self) -> &Vm {
+        &self.vm
+    }
+
+    /// Run commands, one per line, until there are none left or one quits.
+    ///
+    /// When interactive there's a prompt for each command. Otherwise, e.g.
+    /// when replaying a file of commands, each command is echoed instead so
+    /// the output reads as a transcript. Blank lines and lines starting with
+    /// `#` are ignored.
+    pub fn run(
+        &mut self,
+        mut commands: impl BufRead,
+        mut output: impl Write,
+        interactive: bool,
+    ) -> io::Result<()> {
+        self.show(&mut output)?;
+        loop {
+            if interactive {
+                write!(output, "> ")?;
+                output.flush()?;
+            }
+            let mut command = String::new();
+            if commands.read_line(&mut command)? == 0 {
+                return Ok(());
+            }
+            let command = command.trim();
+            if command.is_empty() || command.starts_with('#') {
+                continue;
+            }
+            if !interactive {
+                writeln!(output, "> {command}")?;
+            }
+            if !self.execute(command, &mut output)? {
+                return Ok(());
+            }
+        }
+    }
+
+    // Execute a single command, returning whether to keep going.
+    fn execute(&mut self, command: &str, output: &mut impl Write) -> io::Result<bool> {
+        let mut words = command.split_whitespace();
+        let name = words.next().unwrap_or_default();
+        let arguments: Result<Vec<i64>, _> = words.map(str::parse).collect();
+        let Ok(arguments) = arguments else {
+            writeln!(output, "Arguments must be numbers")?;
+            return Ok(true);
+        };
+        let address = arguments.first().and_then(|&a| usize::try_from(a).ok());
+
+        match (name, &arguments[..], address) {
+            ("step" | "s", [], _) => self.step(1, output)?,
+            ("step" | "s", &[n], _) if n > 0 => self.step(n, output)?,
+            ("continue" | "c", [], _) => self.resume(output)?,
+            ("break" | "b", [], _) => {
+                let breakpoints: Vec<_> =
+                    self.breakpoints.iter().map(|b| format!("{b:04}")).collect();
+                match breakpoints.is_empty() {
+                    true => writeln!(output, "No breakpoints")?,
+                    false => writeln!(output, "Breakpoints: {}", breakpoints.join(" "))?,
+                }
+            }
+            ("break" | "b", [_], Some(address)) => {
+                self.breakpoints.insert(address);
+                writeln!(output, "Breakpoint at {address:04}")?;
+            }
+            ("delete" | "d", [_], Some(address)) => match self.breakpoints.remove(&address) {
+                true => writeln!(output, "Deleted breakpoint at {address:04}")?,
+                false => writeln!(output, "No breakpoint at {address:04}")?,
+            },
+            ("where" | "w", [], _) => self.show(output)?,
+            ("memory" | "m", [_] | [_, _], Some(address)) => {
+                let count = arguments.get(1).copied().unwrap_or(1).max(0);
+                if count > MEMORY_VALUES as i64 {
+                    writeln!(
+                        output,
+                        "Can't show more than {MEMORY_VALUES} values at once"
+                    )?;
+                    return Ok(true);
+                }
+                let start = address as i64;
+                let values = match start.checked_add(count) {
+                    Some(end) => (start..end)
+                        .map(|a| self.vm.load(a).map(|v| v.to_string()))
+                        .collect::<Result<Vec<_>, _>>(),
+                    None => Err(Error::MemoryLimit {
+                        address: start,
+                        limit: self.vm.limit,
+                    }),
+                };
+                match values {
+                    Ok(values) => writeln!(output, "{address:04}: {}", values.join(" "))?,
+                    Err(error) => writeln!(output, "Error: {error}")?,
+                }
+            }
+            ("set", [start, values @ ..], _) if !values.is_empty() => {
+                for (offset, &value) in values.iter().enumerate() {
+                    if let Err(error) = self.vm.store(start + offset as i64, value) {
+                        writeln!(output, "Error: {error}")?;
+                        return Ok(true);
+                    }
+                }
+                writeln!(output, "{start:04}: {}", join(values))?;
+            }
+            ("input" | "i", values, _) if !values.is_empty() => {
+                self.io.input.extend(values);
+                // Input is what a blocked program is waiting for.
+                if self.status == Status::Blocked {
+                    self.status = Status::Running;
+                }
+                writeln!(output, "Queued {}", join(values))?;
+            }
+            ("help" | "h", [], _) => writeln!(output, "{HELP}")?,
+            ("quit" | "q", [], _) => return Ok(false),
+            _ => writeln!(output, "Unknown command: {command} (try help)")?,
+        }
+        Ok(true)
+    }
+
+    fn step(&mut self, count: i64, output: &mut impl Write) -> io::Result<()> {
+        for _ in 0..count {
+            if !self.execute_one(output)? {
+                return Ok(());
+            }
+        }
+        self.show(output)
+    }
+
+    // Execute instructions until the next breakpoint.
+    fn resume(&mut self, output: &mut impl Write) -> io::Result<()> {
+        loop {
+            if !self.execute_one(output)? {
+                return Ok(());
+            }
+            if self.breakpoints.contains(&self.vm.pointer()) {
+                writeln!(output, "Breakpoint at {:04}", self.vm.pointer())?;
+                return self.show(output);
+            }
+        }
+    }
+
+    // Execute a single instruction, returning whether the program can keep
+    // running. Why it can't is shown if not.
+    fn execute_one(&mut self, output: &mut impl Write) -> io::Result<bool> {
+        if self.status != Status::Running {
+            self.show(output)?;
+            return Ok(false);
+        }
+        let result = self.vm.step(&mut self.io);
+        for value in self.io.output.drain(..) {
+            writeln!(output, "Output: {value}")?;
+        }
+        match result {
+            Ok(Status::Running) => Ok(true),
+            Ok(status) => {
+                self.status = status;
+                self.show(output)?;
+                Ok(false)
+            }
+            Err(error) => {
+                writeln!(output, "Error: {error}")?;
+                Ok(false)
+            }
+        }
+    }
+
+    // Show where the program is.
+    fn show(&self, output: &mut impl Write) -> io::Result<()> {
+        let pointer = self.vm.pointer();
+        match self.status {
+            Status::Halted => writeln!(output, "Halted at {pointer:04}"),
+            Status::Blocked => writeln!(output, "Waiting for input at {pointer:04}"),
+            Status::Running => writeln!(
+                output,
+                "{}  ; rb={}",
+                disasm::decode(&self.vm.memory().to_vec(), pointer),
+                self.vm.relative_base()
+            ),
+        }
+    }
+}
+
+fn join(values: &[i64]) -> String {
+    let values: Vec<_> = values.iter().map(i64::to_string).collect();
+    values.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::Debugger;
+    use crate::intcode::Vm;
+
+    fn replay(program: Vec<i64>, script: &str) -> anyhow::Result<String> {
+        let mut output = vec![];
+        Debugger::new(Vm::new(program)).run(script.as_bytes(), &mut output, false)?;
+        Ok(String::from_utf8(output)?)
+    }
+
+    #[test]
+    fn stepping() -> anyhow::Result<()> {
+        let script = "\
+# Step through the example.
+step
+memory 3
+step 5
+";
+        let expected = "\
+0000: ADD [9] [10] -> [3]  ; rb=0
+> step
+0004: MUL [3] [11] -> [0]  ; rb=0
+> memory 3
+0003: 70
+> step 5
+Halted at 0008
+";
+        let program = vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50];
+        assert_eq!(replay(program, script)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn breakpoints() -> anyhow::Result<()> {
+        // Count down from the input, outputting each number.
+        let program = vec![3, 14, 4, 14, 1001, 14, -1, 14, 1005, 14, 2, 99, 0, 0, 0];
+        let script = "\
+break 8
+break
+continue
+input 2
+continue
+continue
+delete 8
+continue
+where
+";
+        let expected = "\
+0000: IN -> [14]  ; rb=0
+> break 8
+Breakpoint at 0008
+> break
+Breakpoints: 0008
+> continue
+Waiting for input at 0000
+> input 2
+Queued 2
+> continue
+Output: 2
+Breakpoint at 0008
+0008: JT [14] 2  ; rb=0
+> continue
+Output: 1
+Breakpoint at 0008
+0008: JT [14] 2  ; rb=0
+> delete 8
+Deleted breakpoint at 0008
+> continue
+Halted at 0011
+> where
+Halted at 0011
+";
+        assert_eq!(replay(program, script)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn patching() -> anyhow::Result<()> {
+        let script = "\
+set 9 2 3
+memory 9 3
+continue
+memory 0
+set -1 0
+memory 9 x
+memory 0 100000000000
+memory 1048575 3
+memory 9223372036854775807 2
+jump 3
+";
+        let expected = "\
+0000: ADD [9] [10] -> [3]  ; rb=0
+> set 9 2 3
+0009: 2 3
+> memory 9 3
+0009: 2 3 50
+> continue
+Halted at 0008
+> memory 0
+0000: 250
+> set -1 0
+Error: Invalid address: -1
+> memory 9 x
+Arguments must be numbers
+> memory 0 100000000000
+Can't show more than 1000 values at once
+> memory 1048575 3
+Error: Address 1048576 is beyond the memory limit of 1048576
+> memory 9223372036854775807 2
+Error: Address 9223372036854775807 is beyond the memory limit of 1048576
+> jump 3
+Unknown command: jump 3 (try help)
+";
+        let program = vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50];
+        assert_eq!(replay(program, script)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn errors() -> anyhow::Result<()> {
+        let expected = "\
+0000: DATA 12  ; rb=0
+> step
+Error: Instruction 12 at 0 has an invalid opcode
+";
+        assert_eq!(replay(vec![12], "step\n")?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn quitting() -> anyhow::Result<()> {
+        let expected = "\
+0000: HALT  ; rb=0
+> quit
+";
+        assert_eq!(replay(vec![99], "quit\nstep\n")?, expected);
+        Ok(())
+    }
+}
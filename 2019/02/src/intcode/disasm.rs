@@ -0,0 +1,351 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use super::{Instruction, Mode, Opcode};
+
+/// How many values are listed on each line of data.
+const DATA_PER_LINE: usize = 8;
+
+/// A line of a listing, starting at an address of the program.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Line {
+    Code {
+        address: usize,
+        instruction: Instruction,
+        parameters: Vec<i64>,
+    },
+    Data {
+        address: usize,
+        values: Vec<i64>,
+    },
+}
+
+impl Line {
+    pub fn address(&self) -> usize {
+        match self {
+            Self::Code { address, .. } | Self::Data { address, .. } => *address,
+        }
+    }
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Code {
+                address,
+                instruction,
+                parameters,
+            } => {
+                write!(f, "{address:04}: {}", instruction.opcode.mnemonic())?;
+                for (n, (&parameter, &mode)) in
+                    parameters.iter().zip(&instruction.modes).enumerate()
+                {
+                    let arrow = if instruction.opcode.writes() == Some(n) {
+                        "-> "
+                    } else {
+                        ""
+                    };
+                    write!(f, " {arrow}{}", Operand(parameter, mode))?;
+                }
+                Ok(())
+            }
+            Self::Data { address, values } => {
+                write!(f, "{address:04}: DATA")?;
+                values.iter().try_for_each(|value| write!(f, " {value}"))
+            }
+        }
+    }
+}
+
+/// The line for whatever is at an address as if it were code, or as data if
+/// it isn't a whole instruction. Memory beyond the end is zero.
+pub fn decode(memory: &[i64], address: usize) -> Line {
+    let value = memory.get(address).copied().unwrap_or_default();
+    match Instruction::decode(address, value) {
+        Ok(instruction) => {
+            let parameters = (1..instruction.opcode.width())
+                .map(|n| memory.get(address + n).copied().unwrap_or_default())
+                .collect();
+            Line::Code {
+                address,
+                instruction,
+                parameters,
+            }
+        }
+        Err(_) => Line::Data {
+            address,
+            values: vec![value],
+        },
+    }
+}
+
+/// A parameter as it's written in a listing: `[9]` in position mode, `9` in
+/// immediate mode and `[rb+9]` in relative mode.
+pub struct Operand(pub i64, pub Mode);
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self(parameter, Mode::Position) => write!(f, "[{parameter}]"),
+            Self(parameter, Mode::Immediate) => write!(f, "{parameter}"),
+            Self(parameter, Mode::Relative) => write!(f, "[rb{parameter:+}]"),
+        }
+    }
+}
+
+/// An annotated listing of a program, along with where each jump which can
+/// be worked out without running the program goes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Listing {
+    pub lines: Vec<Line>,
+    /// The addresses of the jumps to each target.
+    pub targets: BTreeMap<usize, BTreeSet<usize>>,
+}
+
+/// Disassemble a program, following its control flow from address 0 to find
+/// its code. Anything which isn't reached is listed as data.
+pub fn disassemble(program: &[i64]) -> Listing {
+    let code = code(program);
+
+    let mut lines = vec![];
+    let mut data = vec![];
+    let mut address = 0;
+    while address < program.len() {
+        let Some(instruction) = code.get(&address) else {
+            data.push(address);
+            address += 1;
+            continue;
+        };
+        lines.extend(data_lines(program, &data));
+        data.clear();
+
+        // Anything jumping into the middle of this instruction treats part of
+        // it as code too, so list that straight after it.
+        let end = address + instruction.opcode.width();
+        for (&start, instruction) in code.range(address..end) {
+            lines.push(Line::Code {
+                address: start,
+                instruction: *instruction,
+                parameters: parameters(program, start, instruction),
+            });
+        }
+        address = end;
+    }
+    lines.extend(data_lines(program, &data));
+
+    let mut targets: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
+    for (&address, instruction) in &code {
+        if let Some(target) = target(program, address, instruction) {
+            targets.entry(target).or_default().insert(address);
+        }
+    }
+
+    Listing { lines, targets }
+}
+
+impl fmt::Display for Listing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Code which overlaps the instruction before it is commented out, so
+        // the listing still reads as the program from top to bottom.
+        let mut end = 0;
+        for line in &self.lines {
+            match line {
+                Line::Code { address, .. } if *address < end => writeln!(f, "; {line}")?,
+                Line::Code {
+                    address,
+                    instruction,
+                    ..
+                } => {
+                    end = address + instruction.opcode.width();
+                    writeln!(f, "{line}")?;
+                }
+                Line::Data { .. } => writeln!(f, "{line}")?,
+            }
+        }
+
+        if !self.targets.is_empty() {
+            writeln!(f, "\n; Jump targets")?;
+        }
+        for (target, sources) in &self.targets {
+            write!(f, "; {target:04} <-")?;
+            sources
+                .iter()
+                .try_for_each(|source| write!(f, " {source:04}"))?;
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Find every instruction which could be executed, by following each path
+/// through the program from address 0. Jumps which go wherever a value in
+/// memory says can't be followed without running the program.
+pub fn code(program: &[i64]) -> BTreeMap<usize, Instruction> {
+    let mut code = BTreeMap::new();
+    let mut unvisited = vec![0];
+    while let Some(address) = unvisited.pop() {
+        if code.contains_key(&address) || address >= program.len() {
+            continue;
+        }
+        let Ok(instruction) = Instruction::decode(address, program[address]) else {
+            continue;
+        };
+        let next = address + instruction.opcode.width();
+        if next > program.len() {
+            continue;
+        }
+        code.insert(address, instruction);
+
+        let condition = || (instruction.modes[0] == Mode::Immediate).then(|| program[address + 1]);
+        let falls_through = match instruction.opcode {
+            Opcode::Halt => false,
+            Opcode::JumpIfTrue => condition().is_none_or(|value| value == 0),
+            Opcode::JumpIfFalse => condition() != Some(0),
+            _ => true,
+        };
+        if falls_through {
+            unvisited.push(next);
+        }
+        unvisited.extend(target(program, address, &instruction));
+    }
+    code
+}
+
+// Where a jump goes, if that's known without running the program.
+fn target(program: &[i64], address: usize, instruction: &Instruction) -> Option<usize> {
+    let jumps = matches!(instruction.opcode, Opcode::JumpIfTrue | Opcode::JumpIfFalse);
+    if !jumps || instruction.modes[1] != Mode::Immediate {
+        return None;
+    }
+    // A condition known to never be met means there's no jump at all.
+    if instruction.modes[0] == Mode::Immediate {
+        let condition = program[address + 1] != 0;
+        if condition != (instruction.opcode == Opcode::JumpIfTrue) {
+            return None;
+        }
+    }
+    usize::try_from(program[address + 2]).ok()
+}
+
+fn parameters(program: &[i64], address: usize, instruction: &Instruction) -> Vec<i64> {
+    program[address + 1..address + instruction.opcode.width()].to_vec()
+}
+
+// Lines for runs of consecutive data addresses.
+fn data_lines<'a>(program: &'a [i64], addresses: &'a [usize]) -> impl Iterator<Item = Line> + 'a {
+    addresses
+        .chunk_by(|a, b| a + 1 == *b)
+        .flat_map(|run| run.chunks(DATA_PER_LINE))
+        .map(|chunk| Line::Data {
+            address: chunk[0],
+            values: chunk.iter().map(|&address| program[address]).collect(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use super::disassemble;
+
+    #[test_case(&[1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50], "\
+0000: ADD [9] [10] -> [3]
+0004: MUL [3] [11] -> [0]
+0008: HALT
+0009: DATA 30 40 50
+" ; "example")]
+    #[test_case(&[1002, 4, 3, 4, 33], "\
+0000: MUL [4] 3 -> [4]
+0004: DATA 33
+" ; "self-modifying")]
+    #[test_case(&[109, -1, 204, 1, 21101, 2, 3, 0, 3, 0, 99], "\
+0000: ARB -1
+0002: OUT [rb+1]
+0004: ADD 2 3 -> [rb+0]
+0008: IN -> [0]
+0010: HALT
+" ; "relative")]
+    #[test_case(&[1, 0, 0], "\
+0000: DATA 1 0 0
+" ; "truncated")]
+    #[test_case(&[99, 1, 2, 3, 4, 5, 6, 7, 8, 9], "\
+0000: HALT
+0001: DATA 1 2 3 4 5 6 7 8
+0009: DATA 9
+" ; "long data")]
+    fn listing(program: &[i64], expected: &str) {
+        assert_eq!(disassemble(program).to_string(), expected);
+    }
+
+    #[test]
+    fn jump_targets() {
+        let program = [
+            3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0,
+            0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000, 1, 20, 4,
+            20, 1105, 1, 46, 98, 99,
+        ];
+        let expected = "\
+0000: IN -> [21]
+0002: EQ [21] 8 -> [20]
+0006: JT [20] 22
+0009: LT 8 [21] -> [20]
+0013: JF [20] 31
+0016: JF 0 36
+0019: DATA 98 0 0
+0022: MUL [21] 125 -> [20]
+0026: OUT [20]
+0028: JT 1 46
+0031: OUT 999
+0033: JT 1 46
+0036: ADD 1000 1 -> [20]
+0040: OUT [20]
+0042: JT 1 46
+0045: DATA 98
+0046: HALT
+
+; Jump targets
+; 0022 <- 0006
+; 0031 <- 0013
+; 0036 <- 0016
+; 0046 <- 0028 0033 0042
+";
+        assert_eq!(disassemble(&program).to_string(), expected);
+    }
+
+    #[test]
+    fn jumping_into_an_instruction() {
+        let expected = "\
+0000: JT 1 5
+0003: DATA 1101 2
+0005: OUT 7
+0007: HALT
+
+; Jump targets
+; 0005 <- 0000
+";
+        let program = [1105, 1, 5, 1101, 2, 104, 7, 99];
+        assert_eq!(disassemble(&program).to_string(), expected);
+    }
+
+    #[test]
+    fn overlapping_instructions() {
+        // The add at 3 is reached by falling through, and its parameters are
+        // also reached by the jump at 7.
+        let expected = "\
+0000: JF [13] 3
+0003: ADD 2 104 -> [7]
+; 0005: OUT 7
+0007: JT 1 5
+0010: DATA 0 0 0 0
+
+; Jump targets
+; 0003 <- 0000
+; 0005 <- 0007
+";
+        let program = [1006, 13, 3, 1101, 2, 104, 7, 1105, 1, 5, 0, 0, 0, 0];
+        assert_eq!(disassemble(&program).to_string(), expected);
+    }
+}
@@ -0,0 +1,231 @@
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead, StdinLock, Stdout, Write},
+    sync::mpsc::{Receiver, Sender},
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::Error;
+
+/// Where an Intcode computer's input comes from and where its output goes.
+pub trait Io {
+    /// The next input, or `None` if there isn't one yet, in which case the
+    /// computer is blocked until it's run again.
+    fn input(&mut self) -> Result<Option<i64>, Error>;
+
+    fn output(&mut self, value: i64) -> Result<(), Error>;
+}
+
+/// Nothing attached: there's never any input and output is discarded.
+impl Io for () {
+    fn input(&mut self) -> Result<Option<i64>, Error> {
+        Ok(None)
+    }
+
+    fn output(&mut self, _value: i64) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<T: Io + ?Sized> Io for &mut T {
+    fn input(&mut self) -> Result<Option<i64>, Error> {
+        (**self).input()
+    }
+
+    fn output(&mut self, value: i64) -> Result<(), Error> {
+        (**self).output(value)
+    }
+}
+
+/// Input taken from a queue, with output collected in order.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Queue {
+    pub input: VecDeque<i64>,
+    pub output: Vec<i64>,
+}
+
+impl Queue {
+    pub fn new(input: impl IntoIterator<Item = i64>) -> Self {
+        Self {
+            input: input.into_iter().collect(),
+            output: vec![],
+        }
+    }
+}
+
+impl Io for Queue {
+    fn input(&mut self) -> Result<Option<i64>, Error> {
+        Ok(self.input.pop_front())
+    }
+
+    fn output(&mut self, value: i64) -> Result<(), Error> {
+        self.output.push(value);
+        Ok(())
+    }
+}
+
+/// Input and output through a pair of closures.
+pub struct Closures<I, O> {
+    input: I,
+    output: O,
+}
+
+impl<I, O> Closures<I, O>
+where
+    I: FnMut() -> Option<i64>,
+    O: FnMut(i64),
+{
+    pub fn new(input: I, output: O) -> Self {
+        Self { input, output }
+    }
+}
+
+impl<I, O> Io for Closures<I, O>
+where
+    I: FnMut() -> Option<i64>,
+    O: FnMut(i64),
+{
+    fn input(&mut self) -> Result<Option<i64>, Error> {
+        Ok((self.input)())
+    }
+
+    fn output(&mut self, value: i64) -> Result<(), Error> {
+        (self.output)(value);
+        Ok(())
+    }
+}
+
+/// One value per line, read from a reader and written to a writer. The end of
+/// the reader means there's no more input.
+pub struct Lines<R, W> {
+    reader: R,
+    writer: W,
+}
+
+impl<R: BufRead, W: Write> Lines<R, W> {
+    pub fn new(reader: R, writer: W) -> Self {
+        Self { reader, writer }
+    }
+}
+
+impl Lines<StdinLock<'static>, Stdout> {
+    /// Read input from stdin and write output to stdout.
+    pub fn stdio() -> Self {
+        Self::new(io::stdin().lock(), io::stdout())
+    }
+}
+
+impl<R: BufRead, W: Write> Io for Lines<R, W> {
+    fn input(&mut self) -> Result<Option<i64>, Error> {
+        let mut line = String::new();
+        let read = self
+            .reader
+            .read_line(&mut line)
+            .map_err(|error| Error::Io(error.to_string()))?;
+        if read == 0 {
+            return Ok(None);
+        }
+        let line = line.trim();
+        line.parse()
+            .map(Some)
+            .map_err(|_| Error::Io(format!("Invalid input: {line:?}")))
+    }
+
+    fn output(&mut self, value: i64) -> Result<(), Error> {
+        writeln!(self.writer, "{value}")
+            .and_then(|()| self.writer.flush())
+            .map_err(|error| Error::Io(error.to_string()))
+    }
+}
+
+/// Input received from and output sent to other threads. Waiting for input
+/// blocks the thread, so the computer is only blocked once every sender is
+/// gone.
+pub struct Channel {
+    input: Receiver<i64>,
+    output: Sender<i64>,
+}
+
+impl Channel {
+    pub fn new(input: Receiver<i64>, output: Sender<i64>) -> Self {
+        Self { input, output }
+    }
+}
+
+impl Io for Channel {
+    fn input(&mut self) -> Result<Option<i64>, Error> {
+        Ok(self.input.recv().ok())
+    }
+
+    fn output(&mut self, value: i64) -> Result<(), Error> {
+        self.output
+            .send(value)
+            .map_err(|_| Error::Io("Output channel is closed".into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::mpsc, thread};
+
+    use pretty_assertions::assert_eq;
+
+    use super::{Channel, Closures, Lines};
+    use crate::intcode::{Error, Status, Vm};
+
+    // Read two inputs, outputting each one doubled.
+    fn doubler() -> Vm {
+        Vm::new(vec![
+            3, 17, 1002, 17, 2, 17, 4, 17, 3, 17, 1002, 17, 2, 17, 4, 17, 99, 0,
+        ])
+    }
+
+    #[test]
+    fn closures() -> anyhow::Result<()> {
+        let mut inputs = vec![2, 1];
+        let mut outputs = vec![];
+        let mut io = Closures::new(|| inputs.pop(), |value| outputs.push(value));
+        assert_eq!(doubler().run(&mut io)?, Status::Halted);
+        assert_eq!(outputs, vec![2, 4]);
+        Ok(())
+    }
+
+    #[test]
+    fn lines() -> anyhow::Result<()> {
+        let mut output = vec![];
+        let mut io = Lines::new("3\n 4 \n".as_bytes(), &mut output);
+        assert_eq!(doubler().run(&mut io)?, Status::Halted);
+        assert_eq!(String::from_utf8(output)?, "6\n8\n");
+        Ok(())
+    }
+
+    #[test]
+    fn lines_ending_early() -> anyhow::Result<()> {
+        let mut io = Lines::new("3\n".as_bytes(), vec![]);
+        let mut vm = doubler();
+        assert_eq!(vm.run(&mut io)?, Status::Blocked);
+        assert_eq!(vm.pointer(), 8);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_lines() {
+        let mut io = Lines::new("x\n".as_bytes(), vec![]);
+        let error = doubler().run(&mut io).unwrap_err();
+        assert_eq!(error, Error::Io("Invalid input: \"x\"".into()));
+    }
+
+    #[test]
+    fn channels() {
+        let (send_input, input) = mpsc::channel();
+        let (output, receive_output) = mpsc::channel();
+        let vm = thread::spawn(move || doubler().run(&mut Channel::new(input, output)));
+
+        send_input.send(5).unwrap();
+        assert_eq!(receive_output.recv(), Ok(10));
+        send_input.send(6).unwrap();
+        assert_eq!(receive_output.recv(), Ok(12));
+        assert_eq!(vm.join().unwrap(), Ok(Status::Halted));
+    }
+}
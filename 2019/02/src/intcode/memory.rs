@@ -0,0 +1,226 @@
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Index,
+    sync::Arc,
+};
+
+use serde::{Deserialize, Serialize};
+
+// How many words are in a page. A power of two, so finding a word's page is
+// just a shift.
+const PAGE: usize = 256;
+
+/// An Intcode computer's memory, split into pages which copies of it share
+/// until one of them writes to a page. Copying memory only copies a pointer
+/// to each page, and each copy only copies the pages it writes to.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(from = "Vec<i64>", into = "Vec<i64>")]
+pub struct Memory {
+    // Words of the last page beyond the end are always zero.
+    pages: Vec<Arc<[i64; PAGE]>>,
+    len: usize,
+}
+
+impl Memory {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<i64> {
+        match index < self.len {
+            true => Some(self.pages[index / PAGE][index % PAGE]),
+            false => None,
+        }
+    }
+
+    /// Set the word at an index, copying its page first if it's shared.
+    ///
+    /// # Panics
+    ///
+    /// If the index is beyond the end of memory.
+    #[inline]
+    pub fn set(&mut self, index: usize, value: i64) {
+        assert!(index < self.len, "{index} is beyond the end of memory");
+        Arc::make_mut(&mut self.pages[index / PAGE])[index % PAGE] = value;
+    }
+
+    /// Grow memory to a length, filling it with zeros.
+    pub fn grow(&mut self, len: usize) {
+        if len <= self.len {
+            return;
+        }
+        let pages = len.div_ceil(PAGE);
+        self.pages.resize_with(pages, || Arc::new([0; PAGE]));
+        self.len = len;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = i64> + '_ {
+        self.pages
+            .iter()
+            .flat_map(|page| page.iter().copied())
+            .take(self.len)
+    }
+
+    pub fn to_vec(&self) -> Vec<i64> {
+        self.iter().collect()
+    }
+}
+
+impl Clone for Memory {
+    fn clone(&self) -> Self {
+        Self {
+            pages: self.pages.clone(),
+            len: self.len,
+        }
+    }
+
+    /// Copy another memory into this one, writing over the pages this one has
+    /// its own copy of rather than sharing the other's. Running a program
+    /// over and over from the same memory then never copies a page on write.
+    fn clone_from(&mut self, source: &Self) {
+        self.pages.truncate(source.pages.len());
+        for (page, from) in self.pages.iter_mut().zip(&source.pages) {
+            match Arc::get_mut(page) {
+                Some(words) => words.copy_from_slice(&from[..]),
+                None => page.clone_from(from),
+            }
+        }
+        let copied = self.pages.len();
+        self.pages.extend_from_slice(&source.pages[copied..]);
+        self.len = source.len;
+    }
+}
+
+impl From<Vec<i64>> for Memory {
+    fn from(words: Vec<i64>) -> Self {
+        let pages = words
+            .chunks(PAGE)
+            .map(|chunk| {
+                let mut page = [0; PAGE];
+                page[..chunk.len()].copy_from_slice(chunk);
+                Arc::new(page)
+            })
+            .collect();
+        Self {
+            pages,
+            len: words.len(),
+        }
+    }
+}
+
+impl From<Memory> for Vec<i64> {
+    fn from(memory: Memory) -> Self {
+        memory.to_vec()
+    }
+}
+
+impl Index<usize> for Memory {
+    type Output = i64;
+
+    fn index(&self, index: usize) -> &i64 {
+        assert!(index < self.len, "{index} is beyond the end of memory");
+        &self.pages[index / PAGE][index % PAGE]
+    }
+}
+
+impl PartialEq for Memory {
+    fn eq(&self, other: &Self) -> bool {
+        // Words beyond the end are all zero, so whole pages can be compared,
+        // without looking inside the ones which are shared.
+        self.len == other.len
+            && self
+                .pages
+                .iter()
+                .zip(&other.pages)
+                .all(|(a, b)| Arc::ptr_eq(a, b) || a == b)
+    }
+}
+
+impl Eq for Memory {}
+
+impl Hash for Memory {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        self.pages.iter().for_each(|page| page.hash(state));
+    }
+}
+
+impl fmt::Debug for Memory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use super::{Memory, PAGE};
+
+    #[test_case(0 ; "empty")]
+    #[test_case(3 ; "part of a page")]
+    #[test_case(PAGE ; "a page")]
+    #[test_case(PAGE * 2 + 1 ; "pages")]
+    fn round_tripping(len: usize) {
+        let words: Vec<_> = (0..len as i64).collect();
+        let memory = Memory::from(words.clone());
+        assert_eq!(memory.len(), len);
+        assert_eq!(memory.to_vec(), words);
+        assert_eq!(memory.get(len), None);
+    }
+
+    #[test]
+    fn copying_on_write() {
+        let original = Memory::from(vec![1; PAGE * 3]);
+        let mut copy = original.clone();
+        copy.set(PAGE + 1, 2);
+
+        assert_eq!(original[PAGE + 1], 1);
+        assert_eq!(copy[PAGE + 1], 2);
+        assert_ne!(original, copy);
+        // Only the page written to was copied.
+        let shared = |n: usize| Arc::ptr_eq(&original.pages[n], &copy.pages[n]);
+        assert_eq!((shared(0), shared(1), shared(2)), (true, false, true));
+    }
+
+    #[test]
+    fn cloning_into() {
+        let original = Memory::from(vec![1; PAGE * 2]);
+        let mut copy = Memory::from(vec![2; PAGE * 3]);
+        let shared = copy.clone();
+        copy.set(0, 3);
+
+        // The page only the copy had is written over, and the one it shared
+        // is shared with the original instead.
+        let own = Arc::as_ptr(&copy.pages[0]);
+        copy.clone_from(&original);
+        assert_eq!(copy, original);
+        assert_eq!(Arc::as_ptr(&copy.pages[0]), own);
+        assert!(Arc::ptr_eq(&copy.pages[1], &original.pages[1]));
+        assert_eq!(shared, Memory::from(vec![2; PAGE * 3]));
+    }
+
+    #[test]
+    fn growing() {
+        let mut memory = Memory::from(vec![1, 2, 3]);
+        memory.grow(PAGE + 2);
+        memory.set(PAGE + 1, 4);
+        assert_eq!(memory.len(), PAGE + 2);
+        assert_eq!((memory.get(3), memory.get(PAGE + 1)), (Some(0), Some(4)));
+
+        // Growing the same way from scratch gives the same memory.
+        let mut words = vec![0; PAGE + 2];
+        words[..3].copy_from_slice(&[1, 2, 3]);
+        words[PAGE + 1] = 4;
+        assert_eq!(memory, Memory::from(words));
+    }
+}
@@ -0,0 +1,384 @@
+use std::{
+    collections::VecDeque,
+    sync::{Condvar, Mutex, MutexGuard},
+    thread,
+};
+
+use super::{io::Queue, Io, Status, Vm};
+
+/// Why a network stopped before every computer halted.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum Error {
+    #[error("Computer {computer} failed: {error}")]
+    Computer {
+        computer: usize,
+        error: super::Error,
+    },
+    #[error("Deadlocked with computers {0:?} waiting for input which will never come")]
+    Deadlock(Vec<usize>),
+}
+
+// A computer in a network, along with its input and where its output goes.
+struct Node {
+    vm: Vm,
+    io: Queue,
+    target: Option<usize>,
+    status: Status,
+    last_output: Option<i64>,
+}
+
+/// Intcode computers connected by queues, with each one's output feeding
+/// another's input. Output which isn't connected to anything is the output of
+/// the network as a whole.
+pub struct Network {
+    nodes: Vec<Node>,
+    output: Vec<i64>,
+}
+
+impl Network {
+    /// Computers which aren't connected to each other yet.
+    pub fn new(vms: impl IntoIterator<Item = Vm>) -> Self {
+        let nodes = vms
+            .into_iter()
+            .map(|vm| Node {
+                vm,
+                io: Queue::default(),
+                target: None,
+                status: Status::Running,
+                last_output: None,
+            })
+            .collect();
+        Self {
+            nodes,
+            output: vec![],
+        }
+    }
+
+    /// Computers connected one after the other, with the last one's output
+    /// being the network's.
+    pub fn chain(vms: impl IntoIterator<Item = Vm>) -> Self {
+        let mut network = Self::new(vms);
+        for from in 1..network.nodes.len() {
+            network = network.connect(from - 1, from);
+        }
+        network
+    }
+
+    /// Computers connected one after the other, with the last one's output
+    /// feeding back into the first.
+    pub fn feedback_loop(vms: impl IntoIterator<Item = Vm>) -> Self {
+        let network = Self::chain(vms);
+        match network.nodes.len() {
+            0 => network,
+            n => network.connect(n - 1, 0),
+        }
+    }
+
+    /// Send one computer's output to another's input.
+    ///
+    /// # Panics
+    ///
+    /// If either computer isn't in the network.
+    pub fn connect(mut self, from: usize, to: usize) -> Self {
+        assert!(to < self.nodes.len(), "No computer {to} to connect to");
+        self.nodes[from].target = Some(to);
+        self
+    }
+
+    /// Queue input for a computer.
+    ///
+    /// # Panics
+    ///
+    /// If the computer isn't in the network.
+    pub fn send(&mut self, to: usize, values: impl IntoIterator<Item = i64>) {
+        self.nodes[to].io.input.extend(values);
+    }
+
+    pub fn vm(&self, computer: usize) -> Option<&Vm> {
+        self.nodes.get(computer).map(|node| &node.vm)
+    }
+
+    /// The last value a computer output, wherever it went.
+    pub fn last_output(&self, computer: usize) -> Option<i64> {
+        self.nodes.get(computer)?.last_output
+    }
+
+    /// Output which wasn't sent to another computer.
+    pub fn output(&self) -> &[i64] {
+        &self.output
+    }
+
+    /// Run each computer in turn until it halts or is blocked waiting for
+    /// input, over and over until every computer has halted.
+    pub fn run_round_robin(&mut self) -> Result<(), Error> {
+        loop {
+            let mut progressed = false;
+            for computer in 0..self.nodes.len() {
+                let node = &mut self.nodes[computer];
+                let waiting = node.status == Status::Blocked && node.io.input.is_empty();
+                if node.status == Status::Halted || waiting {
+                    continue;
+                }
+                progressed = true;
+                node.status = node
+                    .vm
+                    .run(&mut node.io)
+                    .map_err(|error| Error::Computer { computer, error })?;
+                self.route(computer);
+            }
+            if !progressed {
+                return self.finished();
+            }
+        }
+    }
+
+    /// Run every computer on its own thread at the same time until every
+    /// computer has halted.
+    pub fn run_threaded(&mut self) -> Result<(), Error> {
+        let shared = Shared {
+            state: Mutex::new(State {
+                queues: self
+                    .nodes
+                    .iter_mut()
+                    .map(|node| std::mem::take(&mut node.io.input))
+                    .collect(),
+                waiting: vec![false; self.nodes.len()],
+                finished: vec![false; self.nodes.len()],
+                last_outputs: self.nodes.iter().map(|node| node.last_output).collect(),
+                output: vec![],
+            }),
+            changed: Condvar::new(),
+        };
+
+        let results: Vec<_> = thread::scope(|scope| {
+            let shared = &shared;
+            let threads: Vec<_> = self
+                .nodes
+                .iter_mut()
+                .enumerate()
+                .map(|(computer, node)| {
+                    let mut port = Port {
+                        shared,
+                        computer,
+                        target: node.target,
+                    };
+                    let vm = &mut node.vm;
+                    scope.spawn(move || {
+                        let result = vm.run(&mut port);
+                        let mut state = shared.lock();
+                        state.finished[computer] = true;
+                        shared.changed.notify_all();
+                        result
+                    })
+                })
+                .collect();
+            threads
+                .into_iter()
+                .map(|thread| thread.join().expect("Computer thread panicked"))
+                .collect()
+        });
+
+        let state = shared.state.into_inner().unwrap_or_else(|e| e.into_inner());
+        for (node, (queue, last_output)) in self
+            .nodes
+            .iter_mut()
+            .zip(state.queues.into_iter().zip(state.last_outputs))
+        {
+            node.io.input = queue;
+            node.last_output = last_output;
+        }
+        self.output.extend(state.output);
+        for (computer, result) in results.into_iter().enumerate() {
+            self.nodes[computer].status =
+                result.map_err(|error| Error::Computer { computer, error })?;
+        }
+        self.finished()
+    }
+
+    // Send a computer's output wherever it goes.
+    fn route(&mut self, computer: usize) {
+        let output = std::mem::take(&mut self.nodes[computer].io.output);
+        let Some(&last) = output.last() else {
+            return;
+        };
+        self.nodes[computer].last_output = Some(last);
+        match self.nodes[computer].target {
+            Some(target) => self.nodes[target].io.input.extend(output),
+            None => self.output.extend(output),
+        }
+    }
+
+    // Whether the network stopped because every computer halted, rather than
+    // because some are waiting for input.
+    fn finished(&self) -> Result<(), Error> {
+        let blocked: Vec<_> = (0..self.nodes.len())
+            .filter(|&computer| self.nodes[computer].status == Status::Blocked)
+            .collect();
+        match blocked.is_empty() {
+            true => Ok(()),
+            false => Err(Error::Deadlock(blocked)),
+        }
+    }
+}
+
+// What computers running on their own threads share.
+struct Shared {
+    state: Mutex<State>,
+    changed: Condvar,
+}
+
+struct State {
+    queues: Vec<VecDeque<i64>>,
+    waiting: Vec<bool>,
+    finished: Vec<bool>,
+    last_outputs: Vec<Option<i64>>,
+    output: Vec<i64>,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // Nothing panics while holding the lock.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl State {
+    // Whether no computer can ever make progress again, because each one has
+    // either finished or is waiting for input nobody has sent.
+    fn deadlocked(&self) -> bool {
+        (0..self.queues.len())
+            .all(|n| self.finished[n] || (self.waiting[n] && self.queues[n].is_empty()))
+    }
+}
+
+// A computer's connection to the rest of a threaded network.
+struct Port<'a> {
+    shared: &'a Shared,
+    computer: usize,
+    target: Option<usize>,
+}
+
+impl Io for Port<'_> {
+    fn input(&mut self) -> Result<Option<i64>, super::Error> {
+        let mut state = self.shared.lock();
+        loop {
+            if let Some(value) = state.queues[self.computer].pop_front() {
+                state.waiting[self.computer] = false;
+                return Ok(Some(value));
+            }
+            state.waiting[self.computer] = true;
+            if state.deadlocked() {
+                self.shared.changed.notify_all();
+                return Ok(None);
+            }
+            state = self
+                .shared
+                .changed
+                .wait(state)
+                .unwrap_or_else(|e| e.into_inner());
+        }
+    }
+
+    fn output(&mut self, value: i64) -> Result<(), super::Error> {
+        let mut state = self.shared.lock();
+        match self.target {
+            Some(target) => state.queues[target].push_back(value),
+            None => state.output.push(value),
+        }
+        state.last_outputs[self.computer] = Some(value);
+        self.shared.changed.notify_all();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use super::{Error, Network};
+    use crate::intcode::Vm;
+
+    const AMPLIFIER: [i64; 17] = [
+        3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0,
+    ];
+    const FEEDBACK: [i64; 29] = [
+        3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001, 28, -1, 28,
+        1005, 28, 6, 99, 0, 0, 5,
+    ];
+
+    fn run(network: &mut Network, threaded: bool) -> Result<(), Error> {
+        match threaded {
+            true => network.run_threaded(),
+            false => network.run_round_robin(),
+        }
+    }
+
+    fn amplifiers(program: &[i64], phases: [i64; 5], connect: fn(Vec<Vm>) -> Network) -> Network {
+        let mut network = connect(vec![Vm::new(program.to_vec()); 5]);
+        for (computer, phase) in phases.into_iter().enumerate() {
+            network.send(computer, [phase]);
+        }
+        network.send(0, [0]);
+        network
+    }
+
+    #[test_case(false ; "round robin")]
+    #[test_case(true ; "threaded")]
+    fn chain(threaded: bool) -> anyhow::Result<()> {
+        let mut network = amplifiers(&AMPLIFIER, [4, 3, 2, 1, 0], Network::chain);
+        run(&mut network, threaded)?;
+        assert_eq!(network.output(), [43210]);
+        assert_eq!(network.last_output(0), Some(4));
+        Ok(())
+    }
+
+    #[test_case(false ; "round robin")]
+    #[test_case(true ; "threaded")]
+    fn feedback_loop(threaded: bool) -> anyhow::Result<()> {
+        let mut network = amplifiers(&FEEDBACK, [9, 8, 7, 6, 5], Network::feedback_loop);
+        run(&mut network, threaded)?;
+        assert!(network.output().is_empty());
+        assert_eq!(network.last_output(4), Some(139629729));
+        Ok(())
+    }
+
+    #[test_case(false ; "round robin")]
+    #[test_case(true ; "threaded")]
+    fn deadlock(threaded: bool) {
+        // Each computer waits for the other before saying anything.
+        let program = vec![3, 7, 4, 7, 1105, 1, 0, 0];
+        let mut network = Network::feedback_loop(vec![Vm::new(program); 2]);
+        assert_eq!(
+            run(&mut network, threaded),
+            Err(Error::Deadlock(vec![0, 1]))
+        );
+    }
+
+    #[test_case(false ; "round robin")]
+    #[test_case(true ; "threaded")]
+    fn waiting_on_a_halted_computer(threaded: bool) -> anyhow::Result<()> {
+        // The first computer outputs twice and halts, so the second one
+        // passes on both values and then waits forever for a third.
+        let first = Vm::new(vec![104, 1, 104, 2, 99]);
+        let second = Vm::new(vec![3, 7, 4, 7, 1105, 1, 0, 0]);
+        let mut network = Network::chain([first, second]);
+        assert_eq!(run(&mut network, threaded), Err(Error::Deadlock(vec![1])));
+        assert_eq!(network.output(), [1, 2]);
+        Ok(())
+    }
+
+    #[test_case(false ; "round robin")]
+    #[test_case(true ; "threaded")]
+    fn failing(threaded: bool) {
+        let mut network = Network::chain([Vm::new(vec![104, 1, 99]), Vm::new(vec![3, 0, 12])]);
+        let expected = Error::Computer {
+            computer: 1,
+            error: crate::intcode::Error::Opcode {
+                address: 2,
+                value: 12,
+            },
+        };
+        assert_eq!(run(&mut network, threaded), Err(expected));
+    }
+}
@@ -0,0 +1,362 @@
+use std::{collections::BTreeMap, ops::Range};
+
+use super::{compiled::Compiled, Instruction, Mode, Opcode, Status, Vm, BUDGET, MEMORY_LIMIT};
+
+/// Why a search couldn't even start.
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum Error {
+    #[error("Address {0} is outside of the program")]
+    Address(usize),
+}
+
+/// Find values to put at some addresses of a program which make it leave a
+/// target at address 0 when it halts, each value being within a range.
+///
+/// When there's more than one answer the first is found, ordered by the value
+/// at the first address, then the second, and so on, like trying each in turn
+/// would. Programs which only add and multiply on their way to halting are
+/// solved without running them at all, and any other program is run with
+/// every combination of values until one works.
+pub fn search(
+    program: &[i64],
+    addresses: &[usize],
+    values: Range<i64>,
+    target: i64,
+) -> Result<Option<Vec<i64>>, Error> {
+    if let Some(&address) = addresses.iter().find(|&&a| a >= program.len()) {
+        return Err(Error::Address(address));
+    }
+    // Anything symbolic evaluation gets wrong, e.g. by ignoring an error the
+    // program would have run into, is caught by running it for real.
+    if let Some(inputs) = symbolic(program, addresses, values.clone(), target) {
+        if leaves(&mut compile(program), addresses, &inputs) == Some(target) {
+            return Ok(Some(inputs));
+        }
+    }
+    Ok(brute_force(program, addresses, values, target))
+}
+
+/// Run the program with every combination of values in turn until one
+/// leaves the target at address 0. Combinations which fail don't count.
+///
+/// The program is only decoded once, with each run starting over in the same
+/// copy of it.
+pub fn brute_force(
+    program: &[i64],
+    addresses: &[usize],
+    values: Range<i64>,
+    target: i64,
+) -> Option<Vec<i64>> {
+    let compiled = compile(program);
+    let mut run = compiled.clone();
+    combinations(addresses.len(), values).find(|inputs| {
+        run.clone_from(&compiled);
+        leaves(&mut run, addresses, inputs) == Some(target)
+    })
+}
+
+/// Work out what the program leaves at address 0 as a polynomial of the
+/// values at the addresses, and then solve that for the target.
+///
+/// This only works for programs which add and multiply and then halt, reading
+/// and writing at addresses which don't depend on the values. Anything else
+/// gives `None`, as does there being no solution.
+pub fn symbolic(
+    program: &[i64],
+    addresses: &[usize],
+    values: Range<i64>,
+    target: i64,
+) -> Option<Vec<i64>> {
+    let result = evaluate(program, addresses)?;
+    let n = addresses.len();
+
+    // Going through every value of all but one variable which the result is
+    // linear in leaves a single equation to solve for that variable.
+    let Some((variable, (coefficient, rest))) = (0..n)
+        .rev()
+        .find_map(|v| result.linear_in(v).map(|split| (v, split)))
+    else {
+        return combinations(n, values).find(|inputs| result.evaluate(inputs) == Some(target));
+    };
+
+    let mut solutions = combinations(n - 1, values.clone()).filter_map(|mut inputs| {
+        inputs.insert(variable, 0);
+        let a = coefficient.evaluate(&inputs)?;
+        let b = rest.evaluate(&inputs)?;
+        inputs[variable] = match (a, target.checked_sub(b)?) {
+            (0, 0) => values.start,
+            (0, _) => return None,
+            // Dividing the smallest value by -1 overflows, and its answer
+            // couldn't be one of the values anyway.
+            (a, difference) if difference.checked_rem(a)? == 0 => difference.checked_div(a)?,
+            _ => return None,
+        };
+        values.contains(&inputs[variable]).then_some(inputs)
+    });
+    // Every other variable is gone through in order, so the first solution is
+    // only the first overall if the one being solved for is the last.
+    match variable == n - 1 {
+        true => solutions.next(),
+        false => solutions.min(),
+    }
+}
+
+fn compile(program: &[i64]) -> Compiled {
+    Compiled::new(Vm::new(program.to_vec()).with_budget(BUDGET))
+}
+
+// What a program leaves at address 0 with the given values at addresses.
+fn leaves(compiled: &mut Compiled, addresses: &[usize], inputs: &[i64]) -> Option<i64> {
+    for (&address, &input) in addresses.iter().zip(inputs) {
+        compiled.store(address as i64, input).ok()?;
+    }
+    match compiled.run(&mut ()).ok()? {
+        Status::Halted => compiled.vm().memory().get(0),
+        _ => None,
+    }
+}
+
+// Every combination of n values from a range, in order.
+fn combinations(n: usize, values: Range<i64>) -> impl Iterator<Item = Vec<i64>> {
+    let first = (n == 0 || !values.is_empty()).then(|| vec![values.start; n]);
+    std::iter::successors(first, move |previous| {
+        let mut next = previous.clone();
+        for value in next.iter_mut().rev() {
+            *value += 1;
+            if *value < values.end {
+                return Some(next);
+            }
+            *value = values.start;
+        }
+        None
+    })
+}
+
+// What the program leaves at address 0 in terms of the values at addresses,
+// if it only adds and multiplies before halting.
+fn evaluate(program: &[i64], addresses: &[usize]) -> Option<Polynomial> {
+    let n = addresses.len();
+    // `None` is a value which can't be known without running the program,
+    // e.g. one read from an address which depends on the inputs. That's fine
+    // so long as it's never used.
+    let mut memory: Vec<Option<Polynomial>> = program
+        .iter()
+        .map(|&value| Some(Polynomial::constant(n, value)))
+        .collect();
+    for (variable, &address) in addresses.iter().enumerate() {
+        memory[address] = Some(Polynomial::variable(n, variable));
+    }
+
+    let mut pointer = 0;
+    loop {
+        let opcode = memory.get(pointer)?.as_ref()?.as_constant()?;
+        let instruction = Instruction::decode(pointer, opcode).ok()?;
+        let parameter = |n: usize| memory.get(pointer + 1 + n).cloned();
+        let read = |n| match instruction.modes[n] {
+            Mode::Immediate => parameter(n),
+            Mode::Position => match parameter(n)?.and_then(|p| p.as_constant()) {
+                Some(address) => {
+                    let address = usize::try_from(address).ok()?;
+                    Some(memory.get(address).cloned().unwrap_or_default())
+                }
+                None => Some(None),
+            },
+            Mode::Relative => None,
+        };
+
+        let value = match instruction.opcode {
+            Opcode::Add => read(0)?.zip(read(1)?).and_then(|(a, b)| a.add(&b)),
+            Opcode::Multiply => read(0)?.zip(read(1)?).and_then(|(a, b)| a.mul(&b)),
+            Opcode::Halt => return memory.first()?.clone(),
+            _ => return None,
+        };
+        if instruction.modes[2] != Mode::Position {
+            return None;
+        }
+        let address = usize::try_from(parameter(2)??.as_constant()?).ok()?;
+        if address >= MEMORY_LIMIT {
+            return None;
+        }
+        if address >= memory.len() {
+            memory.resize(address + 1, Some(Polynomial::constant(n, 0)));
+        }
+        memory[address] = value;
+        pointer += instruction.opcode.width();
+    }
+}
+
+/// A polynomial with integer coefficients, as a map from the powers of each
+/// variable in a term to its coefficient.
+#[derive(Debug, Clone, PartialEq)]
+struct Polynomial(BTreeMap<Vec<u32>, i64>);
+
+impl Polynomial {
+    fn constant(variables: usize, value: i64) -> Self {
+        Self::term(vec![0; variables], value)
+    }
+
+    fn variable(variables: usize, variable: usize) -> Self {
+        let mut powers = vec![0; variables];
+        powers[variable] = 1;
+        Self::term(powers, 1)
+    }
+
+    fn term(powers: Vec<u32>, coefficient: i64) -> Self {
+        let mut terms = BTreeMap::new();
+        if coefficient != 0 {
+            terms.insert(powers, coefficient);
+        }
+        Self(terms)
+    }
+
+    fn as_constant(&self) -> Option<i64> {
+        match self.0.iter().next() {
+            None => Some(0),
+            Some((powers, &coefficient)) if self.0.len() == 1 => {
+                powers.iter().all(|&p| p == 0).then_some(coefficient)
+            }
+            Some(_) => None,
+        }
+    }
+
+    fn add(&self, other: &Self) -> Option<Self> {
+        let mut terms = self.0.clone();
+        for (powers, &coefficient) in &other.0 {
+            let sum = terms.get(powers).copied().unwrap_or_default();
+            match sum.checked_add(coefficient)? {
+                0 => terms.remove(powers),
+                sum => terms.insert(powers.clone(), sum),
+            };
+        }
+        Some(Self(terms))
+    }
+
+    fn mul(&self, other: &Self) -> Option<Self> {
+        let mut product = Self(BTreeMap::new());
+        for (left, &a) in &self.0 {
+            for (right, &b) in &other.0 {
+                let powers = left.iter().zip(right).map(|(l, r)| l + r).collect();
+                product = product.add(&Self::term(powers, a.checked_mul(b)?))?;
+            }
+        }
+        Some(product)
+    }
+
+    fn evaluate(&self, values: &[i64]) -> Option<i64> {
+        self.0
+            .iter()
+            .try_fold(0_i64, |sum, (powers, &coefficient)| {
+                let term = powers
+                    .iter()
+                    .zip(values)
+                    .try_fold(coefficient, |term, (&power, &value)| {
+                        term.checked_mul(value.checked_pow(power)?)
+                    })?;
+                sum.checked_add(term)
+            })
+    }
+
+    // Split into `a * x + b`, where neither `a` nor `b` depend on the
+    // variable `x`, if the polynomial is linear in it.
+    fn linear_in(&self, variable: usize) -> Option<(Self, Self)> {
+        let (mut a, mut b) = (BTreeMap::new(), BTreeMap::new());
+        for (powers, &coefficient) in &self.0 {
+            match powers[variable] {
+                0 => b.insert(powers.clone(), coefficient),
+                1 => {
+                    let mut powers = powers.clone();
+                    powers[variable] = 0;
+                    a.insert(powers, coefficient)
+                }
+                _ => return None,
+            };
+        }
+        Some((Self(a), Self(b)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use super::{brute_force, search, symbolic, Error};
+
+    const INPUT: &str = include_str!("../../input.txt");
+
+    #[test]
+    fn part2() -> anyhow::Result<()> {
+        let program = crate::parse(INPUT)?;
+        let expected = brute_force(&program, &[1, 2], 0..100, 19690720);
+        assert!(expected.is_some());
+        assert_eq!(symbolic(&program, &[1, 2], 0..100, 19690720), expected);
+        assert_eq!(search(&program, &[1, 2], 0..100, 19690720)?, expected);
+        Ok(())
+    }
+
+    #[test_case(&[1101, 0, 0, 0, 99], &[1, 2], 0..5, 2, Some(vec![0, 2]) ; "first by address 1")]
+    #[test_case(&[1102, 0, 0, 0, 99], &[1, 2], 0..5, 6, Some(vec![2, 3]) ; "product")]
+    #[test_case(&[1102, 0, 3, 0, 99], &[1], 0..5, 2, None ; "no solution")]
+    #[test_case(&[1101, 0, 0, 0, 99], &[1, 2], -3..3, -5, Some(vec![-3, -2]) ; "negative")]
+    #[test_case(&[1102, 0, 0, 0, 99], &[1, 2], 0..10, 0, Some(vec![0, 0]) ; "anything times zero")]
+    #[test_case(&[1102, 0, 0, 0, 2, 0, 0, 0, 99], &[1, 2], 0..10, 64, Some(vec![1, 8]) ; "square")]
+    #[test_case(&[1101, 0, 0, 0, 99], &[2, 1], 0..10, 3, Some(vec![0, 3]) ; "addresses out of order")]
+    #[test_case(&[1101, 0, 7, 0, 99], &[], 0..10, 7, Some(vec![]) ; "no addresses")]
+    #[test_case(&[102, -1, 5, 0, 99, 0], &[5], -10..10, i64::MIN, None ; "overflowing division")]
+    fn solving(
+        program: &[i64],
+        addresses: &[usize],
+        values: Range<i64>,
+        target: i64,
+        expected: Option<Vec<i64>>,
+    ) -> anyhow::Result<()> {
+        assert_eq!(
+            brute_force(program, addresses, values.clone(), target),
+            expected
+        );
+        assert_eq!(
+            symbolic(program, addresses, values.clone(), target),
+            expected
+        );
+        assert_eq!(search(program, addresses, values, target)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn falling_back() -> anyhow::Result<()> {
+        // Output isn't adding or multiplying, so this has to be run.
+        let program = [104, 0, 1101, 0, 0, 0, 99];
+        assert_eq!(symbolic(&program, &[3, 4], 0..10, 12), None);
+        assert_eq!(search(&program, &[3, 4], 0..10, 12)?, Some(vec![3, 9]));
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_values() -> anyhow::Result<()> {
+        // The first instruction reads from whichever addresses the inputs
+        // are, but what it writes is overwritten before it matters.
+        let program = [1, 0, 0, 0, 1, 1, 2, 0, 99];
+        assert_eq!(symbolic(&program, &[1, 2], 0..10, 12), Some(vec![3, 9]));
+
+        // Here it does matter.
+        let program = [1, 0, 0, 0, 99];
+        assert_eq!(symbolic(&program, &[1, 2], 0..10, 2), None);
+        assert_eq!(search(&program, &[1, 2], 0..10, 2)?, Some(vec![0, 0]));
+        Ok(())
+    }
+
+    #[test]
+    fn writing_beyond_the_memory_limit() -> anyhow::Result<()> {
+        let program = [1101, 0, 0, 1_000_000_000_000, 1101, 0, 0, 0, 99];
+        assert_eq!(symbolic(&program, &[1, 2], 0..10, 5), None);
+        assert_eq!(search(&program, &[1, 2], 0..10, 5)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn address_outside_the_program() {
+        assert_eq!(search(&[99], &[1], 0..10, 0), Err(Error::Address(1)));
+    }
+}
@@ -0,0 +1,130 @@
+use std::io::{self, Read, Write};
+
+use serde::{Deserialize, Serialize};
+
+use super::{io::Queue, Vm};
+
+/// Everything about a computer at a point in time: its memory, pointer and
+/// relative base, along with any input it hasn't read yet and output nobody
+/// has collected.
+///
+/// Restoring a snapshot gives a computer which carries on exactly where the
+/// original was, so many can be forked from the same checkpoint. Snapshots
+/// can be saved as JSON and loaded again later to resume a long run.
+///
+/// Taking and restoring a snapshot is cheap, since memory is shared with the
+/// computer it came from until either of them writes to it, a page at a time.
+/// Forking many computers from one checkpoint only copies the pages each of
+/// them writes to.
+///
+/// States seen while looking for loops aren't saved, so a loaded computer
+/// only looks for loops from where it resumes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub vm: Vm,
+    pub io: Queue,
+}
+
+impl Snapshot {
+    pub fn new(vm: &Vm, io: &Queue) -> Self {
+        Self {
+            vm: vm.clone(),
+            io: io.clone(),
+        }
+    }
+
+    /// A computer and its queues, as they were when the snapshot was taken.
+    pub fn restore(&self) -> (Vm, Queue) {
+        (self.vm.clone(), self.io.clone())
+    }
+
+    pub fn save(&self, writer: impl Write) -> io::Result<()> {
+        serde_json::to_writer(writer, self).map_err(io::Error::from)
+    }
+
+    pub fn load(reader: impl Read) -> io::Result<Self> {
+        serde_json::from_reader(reader).map_err(io::Error::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs::File, io::ErrorKind};
+
+    use pretty_assertions::assert_eq;
+
+    use super::Snapshot;
+    use crate::intcode::{io::Queue, Error, Status, Vm};
+
+    // Output the sum of each pair of inputs, forever.
+    fn summer() -> Vm {
+        Vm::new(vec![
+            3, 13, 3, 14, 1, 13, 14, 15, 4, 15, 1105, 1, 0, 0, 0, 0,
+        ])
+    }
+
+    #[test]
+    fn forking() -> anyhow::Result<()> {
+        let mut vm = summer();
+        let mut io = Queue::new([1, 2, 3]);
+        assert_eq!(vm.run(&mut io)?, Status::Blocked);
+        let checkpoint = Snapshot::new(&vm, &io);
+
+        let forks: Vec<_> = [10, 20]
+            .into_iter()
+            .map(|input| {
+                let (mut vm, mut io) = checkpoint.restore();
+                io.input.push_back(input);
+                vm.run(&mut io).map(|_| io.output)
+            })
+            .collect::<Result<_, _>>()?;
+        assert_eq!(forks, vec![vec![3, 13], vec![3, 23]]);
+
+        // The original carries on regardless.
+        io.input.push_back(30);
+        vm.run(&mut io)?;
+        assert_eq!(io.output, vec![3, 33]);
+        Ok(())
+    }
+
+    #[test]
+    fn resuming() -> anyhow::Result<()> {
+        let mut vm = summer().with_memory_limit(64);
+        let mut io = Queue::new([1, 2, 3]);
+        vm.run(&mut io)?;
+
+        let path = env::temp_dir().join(format!("aoc-snapshot-resuming-{}", std::process::id()));
+        Snapshot::new(&vm, &io).save(File::create(&path)?)?;
+        let snapshot = Snapshot::load(File::open(&path)?)?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(snapshot, Snapshot::new(&vm, &io));
+
+        let (mut resumed, mut resumed_io) = snapshot.restore();
+        assert_eq!(resumed.pointer(), 2);
+        resumed_io.input.push_back(4);
+        resumed.run(&mut resumed_io)?;
+        assert_eq!(resumed_io.output, vec![3, 7]);
+        Ok(())
+    }
+
+    #[test]
+    fn resuming_loop_detection() -> anyhow::Result<()> {
+        let mut vm = Vm::new(vec![1105, 1, 0]).with_loop_detection();
+        vm.step(&mut ())?;
+
+        let mut saved = vec![];
+        Snapshot::new(&vm, &Queue::default()).save(&mut saved)?;
+        assert!(!String::from_utf8(saved.clone())?.contains("seen"));
+
+        let (mut resumed, _) = Snapshot::load(saved.as_slice())?.restore();
+        assert_eq!(resumed.step(&mut ()), Ok(Status::Running));
+        assert_eq!(resumed.step(&mut ()), Err(Error::Loop(0)));
+        Ok(())
+    }
+
+    #[test]
+    fn loading_garbage() {
+        let error = Snapshot::load("{\"vm\": 1}".as_bytes()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,324 @@
+use std::io::Write;
+
+use serde::Serialize;
+
+use super::{disasm::Operand, Error, Instruction, Vm};
+
+/// What an Intcode computer tells a tracer as it executes each instruction.
+///
+/// Every method does nothing by default, and tracing with `()` compiles down
+/// to not tracing at all.
+pub trait Trace {
+    /// An instruction is about to be executed. It may not finish executing if
+    /// it's blocked waiting for input or fails, in which case it's started
+    /// again next time.
+    fn instruction(&mut self, _vm: &Vm, _instruction: Instruction) {}
+
+    /// A parameter's value was read, from an address unless the parameter is
+    /// in immediate mode.
+    fn read(&mut self, _address: Option<i64>, _value: i64) {}
+
+    /// A value was written to an address.
+    fn write(&mut self, _address: i64, _value: i64) {}
+
+    /// The instruction finished executing.
+    fn executed(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Executing the instruction failed, or it couldn't even be started if
+    /// the tracer wasn't told about it.
+    fn failed(&mut self, _error: &Error) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// No tracing at all.
+impl Trace for () {}
+
+impl<T: Trace + ?Sized> Trace for &mut T {
+    fn instruction(&mut self, vm: &Vm, instruction: Instruction) {
+        (**self).instruction(vm, instruction);
+    }
+
+    fn read(&mut self, address: Option<i64>, value: i64) {
+        (**self).read(address, value);
+    }
+
+    fn write(&mut self, address: i64, value: i64) {
+        (**self).write(address, value);
+    }
+
+    fn executed(&mut self) -> Result<(), Error> {
+        (**self).executed()
+    }
+
+    fn failed(&mut self, error: &Error) -> Result<(), Error> {
+        (**self).failed(error)
+    }
+}
+
+/// A value read or written by an instruction.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Access {
+    /// Where the value is, which immediate mode parameters don't have.
+    pub address: Option<i64>,
+    pub value: i64,
+}
+
+/// Everything an instruction did.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    /// How many instructions have been executed, including this one.
+    pub cycle: u64,
+    pub pointer: usize,
+    pub mnemonic: &'static str,
+    /// The parameters as they're written in a listing, e.g. `[9]` or `3`.
+    pub operands: Vec<String>,
+    pub reads: Vec<Access>,
+    pub writes: Vec<Access>,
+    /// Why the instruction failed, if it did.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Records every instruction executed, keeping them all.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Recorder {
+    pub records: Vec<Record>,
+    cycles: u64,
+    current: Option<Record>,
+}
+
+impl Trace for Recorder {
+    fn instruction(&mut self, vm: &Vm, instruction: Instruction) {
+        let pointer = vm.pointer();
+        let operands = (0..instruction.opcode.parameters())
+            .map(|n| {
+                let parameter = vm.load((pointer + 1 + n) as i64).unwrap_or_default();
+                Operand(parameter, instruction.modes[n]).to_string()
+            })
+            .collect();
+        self.current = Some(Record {
+            cycle: self.cycles + 1,
+            pointer,
+            mnemonic: instruction.opcode.mnemonic(),
+            operands,
+            reads: vec![],
+            writes: vec![],
+            error: None,
+        });
+    }
+
+    fn read(&mut self, address: Option<i64>, value: i64) {
+        if let Some(record) = &mut self.current {
+            record.reads.push(Access { address, value });
+        }
+    }
+
+    fn write(&mut self, address: i64, value: i64) {
+        if let Some(record) = &mut self.current {
+            record.writes.push(Access {
+                address: Some(address),
+                value,
+            });
+        }
+    }
+
+    fn executed(&mut self) -> Result<(), Error> {
+        if let Some(record) = self.current.take() {
+            self.cycles = record.cycle;
+            self.records.push(record);
+        }
+        Ok(())
+    }
+
+    // A failed instruction is recorded along with its error, but isn't
+    // counted as executed.
+    fn failed(&mut self, error: &Error) -> Result<(), Error> {
+        if let Some(mut record) = self.current.take() {
+            record.error = Some(error.to_string());
+            self.records.push(record);
+        }
+        Ok(())
+    }
+}
+
+/// Writes a record of every instruction executed as a line of JSON, as soon
+/// as it's executed.
+pub struct Tracer<W> {
+    writer: W,
+    recorder: Recorder,
+}
+
+impl<W: Write> Tracer<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            recorder: Recorder::default(),
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> Trace for Tracer<W> {
+    fn instruction(&mut self, vm: &Vm, instruction: Instruction) {
+        self.recorder.instruction(vm, instruction);
+    }
+
+    fn read(&mut self, address: Option<i64>, value: i64) {
+        self.recorder.read(address, value);
+    }
+
+    fn write(&mut self, address: i64, value: i64) {
+        self.recorder.write(address, value);
+    }
+
+    fn executed(&mut self) -> Result<(), Error> {
+        self.recorder.executed()?;
+        self.flush()
+    }
+
+    fn failed(&mut self, error: &Error) -> Result<(), Error> {
+        self.recorder.failed(error)?;
+        self.flush()
+    }
+}
+
+impl<W: Write> Tracer<W> {
+    // Write whatever was just recorded.
+    fn flush(&mut self) -> Result<(), Error> {
+        let Some(record) = self.recorder.records.pop() else {
+            return Ok(());
+        };
+        serde_json::to_writer(&mut self.writer, &record)
+            .map_err(|error| Error::Io(error.to_string()))?;
+        writeln!(self.writer).map_err(|error| Error::Io(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::{Access, Record, Recorder, Tracer};
+    use crate::intcode::{io::Queue, Status, Vm};
+
+    #[test]
+    fn recording() -> anyhow::Result<()> {
+        let mut recorder = Recorder::default();
+        let mut vm = Vm::new(vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50]);
+        assert_eq!(vm.run_traced(&mut (), &mut recorder)?, Status::Halted);
+
+        let access = |address, value| Access { address, value };
+        let expected = vec![
+            Record {
+                cycle: 1,
+                pointer: 0,
+                mnemonic: "ADD",
+                operands: vec!["[9]".into(), "[10]".into(), "[3]".into()],
+                reads: vec![access(Some(9), 30), access(Some(10), 40)],
+                writes: vec![access(Some(3), 70)],
+                error: None,
+            },
+            Record {
+                cycle: 2,
+                pointer: 4,
+                mnemonic: "MUL",
+                operands: vec!["[3]".into(), "[11]".into(), "[0]".into()],
+                reads: vec![access(Some(3), 70), access(Some(11), 50)],
+                writes: vec![access(Some(0), 3500)],
+                error: None,
+            },
+            Record {
+                cycle: 3,
+                pointer: 8,
+                mnemonic: "HALT",
+                operands: vec![],
+                reads: vec![],
+                writes: vec![],
+                error: None,
+            },
+        ];
+        assert_eq!(recorder.records, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn blocking() -> anyhow::Result<()> {
+        // Blocking on input doesn't count as executing, so the input is only
+        // recorded once it's resumed.
+        let mut recorder = Recorder::default();
+        let mut vm = Vm::new(vec![3, 5, 104, 7, 99, 0]);
+        let mut io = Queue::default();
+        assert_eq!(vm.step_traced(&mut io, &mut recorder)?, Status::Blocked);
+        assert_eq!(recorder.records, vec![]);
+
+        io.input.push_back(4);
+        assert_eq!(vm.run_traced(&mut io, &mut recorder)?, Status::Halted);
+        let summary: Vec<_> = recorder
+            .records
+            .iter()
+            .map(|r| (r.cycle, r.mnemonic, r.reads.len(), r.writes.len()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![(1, "IN", 0, 1), (2, "OUT", 1, 0), (3, "HALT", 0, 0)]
+        );
+        assert_eq!(
+            recorder.records[1].reads,
+            vec![Access {
+                address: None,
+                value: 7
+            }]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn failing() {
+        let mut recorder = Recorder::default();
+        let mut vm = Vm::new(vec![1105, 1, 0]).with_budget(2);
+        assert!(vm.run_traced(&mut (), &mut recorder).is_err());
+        let summary: Vec<_> = recorder
+            .records
+            .iter()
+            .map(|r| (r.cycle, r.error.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, None),
+                (2, None),
+                (3, Some("Gave up at 0 after executing 2 instructions"))
+            ]
+        );
+    }
+
+    #[test]
+    fn writing_a_failure() -> anyhow::Result<()> {
+        let mut tracer = Tracer::new(vec![]);
+        let mut vm = Vm::new(vec![1101, i64::MAX, 1, 0, 99]);
+        assert!(vm.run_traced(&mut (), &mut tracer).is_err());
+        let expected = r#"{"cycle":1,"pointer":0,"mnemonic":"ADD","operands":["9223372036854775807","1","[0]"],"reads":[{"address":null,"value":9223372036854775807},{"address":null,"value":1}],"writes":[],"error":"ADD at 0 overflowed"}
+"#;
+        assert_eq!(String::from_utf8(tracer.into_inner())?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn writing() -> anyhow::Result<()> {
+        let mut tracer = Tracer::new(vec![]);
+        let mut vm = Vm::new(vec![109, 3, 21101, 2, 3, 1, 99]);
+        vm.run_traced(&mut (), &mut tracer)?;
+        let expected = r#"{"cycle":1,"pointer":0,"mnemonic":"ARB","operands":["3"],"reads":[{"address":null,"value":3}],"writes":[]}
+{"cycle":2,"pointer":2,"mnemonic":"ADD","operands":["2","3","[rb+1]"],"reads":[{"address":null,"value":2},{"address":null,"value":3}],"writes":[{"address":4,"value":5}]}
+{"cycle":3,"pointer":6,"mnemonic":"HALT","operands":[],"reads":[],"writes":[]}
+"#;
+        assert_eq!(String::from_utf8(tracer.into_inner())?, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+pub mod intcode;
+
+use anyhow::anyhow;
+
+use aoc_core::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub use intcode::{run, Error};
+
+pub fn parse(code: &str) -> Result<Vec<i64>, ParseError> {
+    let code = code.trim();
+    code.split(',')
+        .map(|c| parse::value::<i64>(code, c))
+        .collect::<Result<Vec<_>, ParseError>>()
+}
+
+// Set the noun and verb at addresses 1 and 2 of a program.
+fn with_inputs(mut program: Vec<i64>, noun: i64, verb: i64) -> anyhow::Result<Vec<i64>> {
+    let [_, n, v, ..] = &mut program[..] else {
+        return Err(anyhow!("The program is too short to have a noun and verb"));
+    };
+    (*n, *v) = (noun, verb);
+    Ok(program)
+}
+
+pub fn part1(program: Vec<i64>) -> anyhow::Result<i64> {
+    let memory = run(with_inputs(program, 12, 2)?)?;
+    Ok(memory[0])
+}
+
+pub fn part2(program: Vec<i64>) -> anyhow::Result<i64> {
+    let inputs = intcode::search::search(&program, &[1, 2], 0..100, 19690720)?
+        .ok_or_else(|| anyhow!("No noun and verb which yield 19690720"))?;
+    Ok(100 * inputs[0] + inputs[1])
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 2;
+
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc_core::Error> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, aoc_core::Error> {
+        Ok(part1(input.clone())?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, aoc_core::Error> {
+        Ok(part2(input.clone())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test]
+    fn parsing() -> anyhow::Result<()> {
+        let code = "1,9,10,3,2,3,11,0,99,30,40,50";
+        let expected = vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50];
+        let program = parse(code)?;
+
+        assert_eq!(program, expected);
+        Ok(())
+    }
+
+    #[test_case("1,9,x,3", 4..5 ; "invalid")]
+    #[test_case("1,9,,3", 4..4 ; "empty")]
+    #[test_case("1,9,99999999999999999999,3", 4..24 ; "too large")]
+    fn parsing_errors(code: &str, columns: std::ops::Range<usize>) {
+        let error = parse(code).unwrap_err();
+        assert_eq!(error.columns, columns);
+    }
+
+    #[test_case(vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50], vec![3500, 9, 10, 70, 2, 3, 11, 0, 99, 30, 40, 50] ; "program 1")]
+    #[test_case(vec![1, 0, 0, 0, 99], vec![2, 0, 0, 0, 99] ; "program 2")]
+    #[test_case(vec![2, 3, 0, 3, 99], vec![2, 3, 0, 6, 99] ; "program 3")]
+    #[test_case(vec![2, 4, 4, 5, 99, 0], vec![2, 4, 4, 5, 99, 9801] ; "program 4")]
+    #[test_case(vec![1, 1, 1, 4, 99, 5, 6, 0, 99], vec![30, 1, 1, 4, 2, 5, 6, 0, 99] ; "program 5")]
+    fn running(program: Vec<i64>, expected: Vec<i64>) -> anyhow::Result<()> {
+        let memory = run(program)?;
+        assert_eq!(memory, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn too_short_for_inputs() {
+        let error = part1(vec![99]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The program is too short to have a noun and verb"
+        );
+    }
+
+    #[test_case("ADD [x] [y] -> [3]\nMUL [3] [z] -> [0]\nHALT\nx: DATA 30\ny: DATA 40\nz: DATA 50", vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50] ; "program 1")]
+    #[test_case("ADD [0] [0] -> [0]\nHALT", vec![1, 0, 0, 0, 99] ; "program 2")]
+    #[test_case("MUL [3] [0] -> [3]\nHALT", vec![2, 3, 0, 3, 99] ; "program 3")]
+    #[test_case("MUL [4] [4] -> [square]\nHALT\nsquare: DATA 0", vec![2, 4, 4, 5, 99, 0] ; "program 4")]
+    #[test_case("ADD [1] [1] -> [4]\nDATA 99 5 6 0 99", vec![1, 1, 1, 4, 99, 5, 6, 0, 99] ; "program 5")]
+    fn assembling(source: &str, program: Vec<i64>) -> anyhow::Result<()> {
+        assert_eq!(intcode::asm::assemble(source)?, program);
+        Ok(())
+    }
+}
@@ -1,115 +1,244 @@
-use std::{fs, num::ParseIntError, path::PathBuf};
-
-use anyhow::anyhow;
-use clap::Parser;
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter},
+    ops::Range,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc_core::{
+    cli::{self, Options},
+    input::Source,
+    report::{self, Record},
+    Solution,
+};
+use clap::{error::ErrorKind, ArgGroup, CommandFactory, Parser};
+
+use aoc_2019_02::{
+    intcode::{
+        ascii::{Ascii, Transcript},
+        asm,
+        debug::Debugger,
+        disasm,
+        io::Lines,
+        search::search,
+        trace::Tracer,
+        Error, Status, Vm,
+    },
+    parse, Puzzle,
+};
 
 #[derive(Debug, Parser)]
+#[command(group(ArgGroup::new("running").args(["debug", "trace", "ascii"])))]
 struct Args {
-    input: PathBuf,
+    #[command(flatten)]
+    options: Options,
+    /// Print an annotated listing of the program instead of solving it
+    #[arg(long)]
+    disassemble: bool,
+    /// Assemble the input into a program instead of solving it
+    #[arg(long, conflicts_with = "disassemble")]
+    assemble: bool,
+    /// Step through the program in a debugger, reading commands from stdin
+    #[arg(long, conflicts_with_all = ["disassemble", "assemble"])]
+    debug: bool,
+    /// Read debugger commands from a file instead of stdin
+    #[arg(long, requires = "debug")]
+    script: Option<PathBuf>,
+    /// Run the program as it is, with input from stdin, and write a line of
+    /// JSON to a file for every instruction executed
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["disassemble", "assemble", "debug"])]
+    trace: Option<PathBuf>,
+    /// Talk to the program in ASCII text, with its output written to the
+    /// terminal and each line typed as input
+    #[arg(long, conflicts_with_all = ["disassemble", "assemble", "debug", "trace"])]
+    ascii: bool,
+    /// Save the ASCII session to a file, as a line of JSON per input or output
+    #[arg(
+        long,
+        value_name = "FILE",
+        requires = "ascii",
+        conflicts_with = "replay"
+    )]
+    record: Option<PathBuf>,
+    /// Input the lines of a recorded ASCII session instead of reading them
+    /// from the terminal, failing if the output isn't the same as recorded
+    #[arg(long, value_name = "FILE", requires = "ascii")]
+    replay: Option<PathBuf>,
+    /// Stop debugging, tracing or an ASCII session after executing this many
+    /// instructions
+    #[arg(long, value_name = "INSTRUCTIONS", requires = "running")]
+    budget: Option<u64>,
+    /// Stop debugging, tracing or an ASCII session as soon as the program is
+    /// stuck in a loop
+    #[arg(long, requires = "running")]
+    detect_loops: bool,
+    /// Find values for some addresses which make the program leave this at
+    /// address 0 when it halts, instead of solving the puzzle
+    #[arg(long, allow_negative_numbers = true, conflicts_with_all = ["disassemble", "assemble", "debug", "trace", "ascii"])]
+    target: Option<i64>,
+    /// The addresses to find values for, separated by commas
+    #[arg(
+        long,
+        value_delimiter = ',',
+        default_value = "1,2",
+        requires = "target"
+    )]
+    addresses: Vec<usize>,
+    /// The range of values to try at each address, e.g. 0..100
+    #[arg(long, value_name = "START..END", value_parser = range, default_value = "0..100", requires = "target")]
+    values: Range<i64>,
 }
 
-#[derive(Debug, thiserror::Error)]
-enum Error {
-    #[error("Invalid opcode: {0}")]
-    Opcode(u64),
+impl Args {
+    // Whatever is set to read from stdin other than the program.
+    fn stdin(&self) -> Option<&'static str> {
+        if self.debug && self.script.is_none() {
+            return Some("The debugger reads commands");
+        }
+        if self.trace.is_some() {
+            return Some("Tracing reads the program's input");
+        }
+        if self.ascii && self.replay.is_none() {
+            return Some("An ASCII session reads lines");
+        }
+        None
+    }
 }
 
-fn parse(code: &str) -> Result<Vec<u64>, ParseIntError> {
-    code.trim()
-        .split(',')
-        .map(|c| c.parse::<u64>())
-        .collect::<Result<Vec<_>, ParseIntError>>()
+fn main() -> ExitCode {
+    let args = Args::parse();
+    if let (Some(reader), Source::Stdin) = (args.stdin(), args.options.source()) {
+        Args::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                format!("{reader} from stdin, so the program must be a file or --text"),
+            )
+            .exit();
+    }
+    let input = match args.options.source().read() {
+        Ok(input) => input,
+        Err(error) => {
+            let records = Record::failed(Puzzle::YEAR, Puzzle::DAY, error);
+            return cli::print(args.options.format, &records);
+        }
+    };
+
+    let output = if args.disassemble {
+        disassemble(&input)
+    } else if args.assemble {
+        assemble(&input)
+    } else if args.debug {
+        vm(&args, &input)
+            .and_then(|vm| debug(vm, args.script.as_deref()))
+            .map(|()| String::new())
+    } else if args.ascii {
+        vm(&args, &input)
+            .and_then(|vm| ascii(vm, args.record.as_deref(), args.replay.as_deref()))
+            .map(|()| String::new())
+    } else if let Some(target) = args.target {
+        find(&input, &args.addresses, args.values.clone(), target)
+    } else if let Some(path) = &args.trace {
+        vm(&args, &input)
+            .and_then(|vm| trace(vm, path))
+            .map(|()| String::new())
+    } else {
+        return cli::print(args.options.format, &report::records::<Puzzle>(&input));
+    };
+
+    match output {
+        Ok(output) => {
+            print!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Error: {error}");
+            ExitCode::FAILURE
+        }
+    }
 }
 
-fn run(mut memory: Vec<u64>) -> Result<Vec<u64>, Error> {
-    let mut pointer = 0;
-    loop {
-        // Note: Indexing could panic if given an invalid program.
-        let opcode = memory[pointer];
-        match opcode {
-            1 => {
-                let address1 = memory[pointer + 1] as usize;
-                let address2 = memory[pointer + 2] as usize;
-                let address3 = memory[pointer + 3] as usize;
+fn disassemble(input: &str) -> anyhow::Result<String> {
+    let program = parse(input)?;
+    Ok(disasm::disassemble(&program).to_string())
+}
 
-                let left_addend = memory[address1];
-                let right_addend = memory[address2];
-                memory[address3] = left_addend + right_addend;
-            }
-            2 => {
-                let address1 = memory[pointer + 1] as usize;
-                let address2 = memory[pointer + 2] as usize;
-                let address3 = memory[pointer + 3] as usize;
+fn assemble(input: &str) -> anyhow::Result<String> {
+    let program: Vec<_> = asm::assemble(input)?.iter().map(i64::to_string).collect();
+    Ok(format!("{}\n", program.join(",")))
+}
 
-                let multiplier = memory[address1];
-                let multiplicand = memory[address2];
-                memory[address3] = multiplier * multiplicand;
-            }
-            99 => break,
-            _ => return Err(Error::Opcode(opcode)),
-        }
-        pointer += 4;
-    }
-    Ok(memory)
+fn find(
+    input: &str,
+    addresses: &[usize],
+    values: Range<i64>,
+    target: i64,
+) -> anyhow::Result<String> {
+    let program = parse(input)?;
+    let inputs = search(&program, addresses, values, target)?
+        .ok_or_else(|| anyhow::anyhow!("No values at addresses {addresses:?} yield {target}"))?;
+    let inputs: Vec<_> = addresses
+        .iter()
+        .zip(inputs)
+        .map(|(address, value)| format!("{address}={value}"))
+        .collect();
+    Ok(format!("{}\n", inputs.join(" ")))
 }
 
-fn part1(mut program: Vec<u64>) -> anyhow::Result<u64> {
-    program[1] = 12;
-    program[2] = 2;
-    let memory = run(program)?;
-    Ok(memory[0])
+fn range(text: &str) -> Result<Range<i64>, String> {
+    let (start, end) = text
+        .split_once("..")
+        .ok_or_else(|| format!("{text} isn't a range like 0..100"))?;
+    let bound = |b: &str| b.parse::<i64>().map_err(|e| format!("{b}: {e}"));
+    Ok(bound(start)?..bound(end)?)
 }
 
-fn part2(program: Vec<u64>) -> anyhow::Result<u64> {
-    for noun in 0..100 {
-        for verb in 0..100 {
-            let mut memory = program.clone();
-            memory[1] = noun;
-            memory[2] = verb;
-            if run(memory)?[0] == 19690720 {
-                return Ok(100 * noun + verb);
-            }
-        }
+// A computer with the program, set up as asked.
+fn vm(args: &Args, input: &str) -> anyhow::Result<Vm> {
+    let mut vm = Vm::new(parse(input)?);
+    if let Some(budget) = args.budget {
+        vm = vm.with_budget(budget);
     }
-    Err(anyhow!("No noun and verb which yield 19690720"))
+    if args.detect_loops {
+        vm = vm.with_loop_detection();
+    }
+    Ok(vm)
 }
 
-fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
-    let code = fs::read_to_string(args.input)?;
-    let program = parse(&code)?;
-
-    println!("Part 1: {}", part1(program.clone())?);
-    println!("Part 2: {}", part2(program)?);
-
+fn debug(vm: Vm, script: Option<&Path>) -> anyhow::Result<()> {
+    let mut debugger = Debugger::new(vm);
+    match script {
+        Some(script) => debugger.run(BufReader::new(File::open(script)?), io::stdout(), false)?,
+        None => debugger.run(io::stdin().lock(), io::stdout(), true)?,
+    }
     Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use pretty_assertions::assert_eq;
-    use test_case::test_case;
-
-    use super::*;
-
-    #[test]
-    fn parsing() -> anyhow::Result<()> {
-        let code = "1,9,10,3,2,3,11,0,99,30,40,50";
-        let expected = vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50];
-        let program = parse(code)?;
-
-        assert_eq!(program, expected);
-        Ok(())
+fn trace(mut vm: Vm, path: &Path) -> anyhow::Result<()> {
+    let mut tracer = Tracer::new(BufWriter::new(File::create(path)?));
+    let status = vm.run_traced(&mut Lines::stdio(), &mut tracer);
+    tracer.into_inner().into_inner()?;
+    match status? {
+        Status::Blocked => Err(Error::NoInput(vm.pointer()).into()),
+        _ => Ok(()),
     }
+}
 
-    #[test_case(vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50], vec![3500, 9, 10, 70, 2, 3, 11, 0, 99, 30, 40, 50] ; "program 1")]
-    #[test_case(vec![1, 0, 0, 0, 99], vec![2, 0, 0, 0, 99] ; "program 2")]
-    #[test_case(vec![2, 3, 0, 3, 99], vec![2, 3, 0, 6, 99] ; "program 3")]
-    #[test_case(vec![2, 4, 4, 5, 99, 0], vec![2, 4, 4, 5, 99, 9801] ; "program 4")]
-    #[test_case(vec![1, 1, 1, 4, 99, 5, 6, 0, 99], vec![30, 1, 1, 4, 2, 5, 6, 0, 99] ; "program 5")]
-    fn running(program: Vec<u64>, expected: Vec<u64>) -> anyhow::Result<()> {
-        let memory = run(program)?;
-        assert_eq!(memory, expected);
-        Ok(())
+fn ascii(mut vm: Vm, record: Option<&Path>, replay: Option<&Path>) -> anyhow::Result<()> {
+    if let Some(path) = replay {
+        let recording = Transcript::load(File::open(path)?)?;
+        return match recording.replay(&mut vm, io::stdout())? == recording {
+            true => Ok(()),
+            false => Err(anyhow::anyhow!("The output differs from the recording")),
+        };
+    }
+    let mut io = Ascii::stdio();
+    let status = vm.run(&mut io);
+    if let Some(path) = record {
+        io.transcript().save(BufWriter::new(File::create(path)?))?;
+    }
+    match status? {
+        Status::Blocked => Err(Error::NoInput(vm.pointer()).into()),
+        _ => Ok(()),
     }
 }
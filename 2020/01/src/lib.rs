@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use aoc_core::{parse, Error, Solution};
+
+/// Find the product of the first two numbers in the input set which sum to
+/// `2020`, if they exist.
+pub fn part1(numbers: &HashSet<i32>) -> Option<i32> {
+    for number in numbers {
+        let target = 2020 - number;
+        if numbers.contains(&target) {
+            return Some(number * target);
+        }
+    }
+    None
+}
+
+/// Find the product of the first three numbers in the input set which sum to
+/// `2020`, if they exist.
+pub fn part2(numbers: &HashSet<i32>) -> Option<i32> {
+    for outer in numbers {
+        for inner in numbers {
+            let target = 2020 - outer - inner;
+            if numbers.contains(&target) {
+                return Some(inner * outer * target);
+            }
+        }
+    }
+    None
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 1;
+
+    type Input = HashSet<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        let numbers = parse::lines(input.lines(), |line| parse::value(line, line))?;
+        Ok(numbers.into_iter().collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Error> {
+        part1(input).ok_or(Error::msg("no solution was found"))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Error> {
+        part2(input).ok_or(Error::msg("no solution was found"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{part1, part2};
+
+    #[test]
+    fn test_part1_solution() {
+        // 1721 + 299 = 2020, so we expect 1721 * 299.
+        let numbers = [1721, 979, 366, 299, 675, 1456].iter().cloned().collect();
+        assert_eq!(part1(&numbers), Some(514_579));
+    }
+
+    #[test]
+    fn test_part1_no_solution() {
+        // 299 has been removed from this set, so there is no solution.
+        let numbers = [1721, 979, 366, 675, 1456].iter().cloned().collect();
+        assert_eq!(part1(&numbers), None);
+    }
+
+    #[test]
+    fn test_part2_solution() {
+        // 979 + 366 + 675 = 2020, so we expect 979 * 366 * 675.
+        let numbers = [1721, 979, 366, 299, 675, 1456].iter().cloned().collect();
+        assert_eq!(part2(&numbers), Some(241_861_950));
+    }
+
+    #[test]
+    fn test_part2_no_solution() {
+        // 675 has been removed from this set, so there is no solution.
+        let numbers = [1721, 979, 366, 299, 1456].iter().cloned().collect();
+        assert_eq!(part2(&numbers), None);
+    }
+}
@@ -0,0 +1,261 @@
+use std::convert::TryFrom;
+
+use thiserror::Error;
+
+use aoc_core::parse::{self, ParseError};
+use aoc_core::{Error, Solution};
+
+#[derive(PartialEq, Debug)]
+pub struct PasswordEntry<'a> {
+    low: usize,
+    high: usize,
+    letter: char,
+    password: &'a str,
+}
+
+impl<'a> PasswordEntry<'a> {
+    /// Whether the password contains a number of matches for `letter` within
+    /// the `low..high` range.
+    pub fn valid_range(&self) -> bool {
+        (self.low..=self.high).contains(&self.password.matches(self.letter).count())
+    }
+
+    /// Whether the password has matches for `letter` at either the `low` or
+    /// `high` index, but not both!
+    pub fn valid_indices(&self) -> bool {
+        let (low, high) = self
+            .password
+            .chars()
+            .enumerate()
+            .map(|(index, letter)| (index + 1, letter))
+            .fold((false, false), |(mut low, mut high), (index, letter)| {
+                match (index, letter == self.letter) {
+                    (idx, true) if idx == self.low => low = true,
+                    (idx, true) if idx == self.high => high = true,
+                    _ => {}
+                }
+                (low, high)
+            });
+        low ^ high
+    }
+}
+
+/// Why a line isn't a valid password entry.
+#[derive(Error, Debug, PartialEq)]
+pub enum PasswordEntryError {
+    #[error("expected a range like 1-3")]
+    Range,
+    #[error("expected a letter like a:")]
+    Letter,
+    #[error("expected a password")]
+    Password,
+}
+
+impl<'a> TryFrom<&'a str> for PasswordEntry<'a> {
+    type Error = ParseError;
+
+    fn try_from(string: &'a str) -> Result<Self, Self::Error> {
+        let mut parts = string.split(' ');
+        let range = parts
+            .next()
+            .ok_or_else(|| ParseError::line(PasswordEntryError::Range, string))?;
+        let (low, high) = range
+            .split_once('-')
+            .ok_or_else(|| ParseError::new(PasswordEntryError::Range, string, range))?;
+
+        let low: usize = parse::value(string, low)?;
+        let high: usize = parse::value(string, high)?;
+        let letter = parts
+            .next()
+            .ok_or_else(|| ParseError::end(PasswordEntryError::Letter, string))?;
+        let letter: char = parse::value(string, letter.trim_end_matches(':'))?;
+        let password: &str = parts
+            .next()
+            .ok_or_else(|| ParseError::end(PasswordEntryError::Password, string))?;
+
+        Ok(Self {
+            low,
+            high,
+            letter,
+            password,
+        })
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 2;
+
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        // Entries borrow from their line, so they're parsed again by each part.
+        let lines = input.lines().map(String::from).collect::<Vec<_>>();
+        parse::lines(&lines, |line| PasswordEntry::try_from(line).map(drop))?;
+        Ok(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Error> {
+        let mut valid = 0;
+        for line in input {
+            if PasswordEntry::try_from(line.as_ref())?.valid_range() {
+                valid += 1;
+            }
+        }
+        Ok(valid)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Error> {
+        let mut valid = 0;
+        for line in input {
+            if PasswordEntry::try_from(line.as_ref())?.valid_indices() {
+                valid += 1;
+            }
+        }
+        Ok(valid)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{PasswordEntry, PasswordEntryError};
+    use std::convert::TryFrom;
+    use std::num::ParseIntError;
+
+    #[test]
+    fn test_password_entry_from_str() {
+        let given = vec![
+            (
+                "1-3 a: abcde",
+                PasswordEntry {
+                    low: 1,
+                    high: 3,
+                    letter: 'a',
+                    password: "abcde",
+                },
+            ),
+            (
+                "1-3 b: cdefg",
+                PasswordEntry {
+                    low: 1,
+                    high: 3,
+                    letter: 'b',
+                    password: "cdefg",
+                },
+            ),
+            (
+                "2-9 c: ccccccccc",
+                PasswordEntry {
+                    low: 2,
+                    high: 9,
+                    letter: 'c',
+                    password: "ccccccccc",
+                },
+            ),
+        ];
+
+        for (string, entry) in given {
+            assert_eq!(PasswordEntry::try_from(string).unwrap(), entry);
+        }
+    }
+
+    #[test]
+    fn test_password_entry_errors() {
+        let given = vec![
+            ("1 a: abcde", 0..1, Some(PasswordEntryError::Range)),
+            ("1-x a: abcde", 2..3, None),
+            ("1-3", 3..3, Some(PasswordEntryError::Letter)),
+            ("1-3 ab: abcde", 4..6, None),
+            ("1-3 a:", 6..6, Some(PasswordEntryError::Password)),
+        ];
+
+        for (string, columns, reason) in given {
+            let error = PasswordEntry::try_from(string).unwrap_err();
+            assert_eq!(error.columns, columns);
+            if let Some(reason) = reason {
+                assert_eq!(error.reason_is(), Some(&reason));
+            }
+        }
+
+        let error = PasswordEntry::try_from("1-x a: abcde").unwrap_err();
+        assert!(error.reason_is::<ParseIntError>().is_some());
+    }
+
+    #[test]
+    fn test_valid_range() {
+        let given = vec![
+            (
+                PasswordEntry {
+                    low: 1,
+                    high: 3,
+                    letter: 'a',
+                    password: "abcde",
+                },
+                true,
+            ),
+            (
+                PasswordEntry {
+                    low: 1,
+                    high: 3,
+                    letter: 'b',
+                    password: "cdefg",
+                },
+                false,
+            ),
+            (
+                PasswordEntry {
+                    low: 2,
+                    high: 9,
+                    letter: 'c',
+                    password: "ccccccccc",
+                },
+                true,
+            ),
+        ];
+
+        for (entry, expected) in given {
+            assert_eq!(entry.valid_range(), expected);
+        }
+    }
+
+    #[test]
+    fn test_valid_indices() {
+        let given = vec![
+            (
+                PasswordEntry {
+                    low: 1,
+                    high: 3,
+                    letter: 'a',
+                    password: "abcde",
+                },
+                true,
+            ),
+            (
+                PasswordEntry {
+                    low: 1,
+                    high: 3,
+                    letter: 'b',
+                    password: "cdefg",
+                },
+                false,
+            ),
+            (
+                PasswordEntry {
+                    low: 2,
+                    high: 9,
+                    letter: 'c',
+                    password: "ccccccccc",
+                },
+                false,
+            ),
+        ];
+
+        for (entry, expected) in given {
+            assert_eq!(entry.valid_indices(), expected);
+        }
+    }
+}
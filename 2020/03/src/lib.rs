@@ -0,0 +1,70 @@
+use aoc_core::{Error, Solution};
+
+pub fn trees(slope: &Vec<Vec<char>>, right: usize, down: usize) -> u32 {
+    match slope.iter().nth(0) {
+        Some(first) => slope
+            .iter()
+            .step_by(down)
+            .enumerate()
+            .map(|(idx, row)| match row[(idx * right) % first.len()] {
+                '#' => 1,
+                _ => 0,
+            })
+            .sum(),
+        None => 0,
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<char>>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.lines().map(|line| line.chars().collect()).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Error> {
+        Ok(trees(input, 3, 1))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Error> {
+        Ok([(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+            .iter()
+            .map(|&(right, down)| trees(input, right, down))
+            .product())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::trees;
+
+    #[test]
+    fn test_trees() {
+        let slope = vec![
+            vec!['.', '.', '#', '#', '.', '.', '.', '.', '.', '.', '.'],
+            vec!['#', '.', '.', '.', '#', '.', '.', '.', '#', '.', '.'],
+            vec!['.', '#', '.', '.', '.', '.', '#', '.', '.', '#', '.'],
+            vec!['.', '.', '#', '.', '#', '.', '.', '.', '#', '.', '#'],
+            vec!['.', '#', '.', '.', '.', '#', '#', '.', '.', '#', '.'],
+            vec!['.', '.', '#', '.', '#', '#', '.', '.', '.', '.', '.'],
+            vec!['.', '#', '.', '#', '.', '#', '.', '.', '.', '.', '#'],
+            vec!['.', '#', '.', '.', '.', '.', '.', '.', '.', '.', '#'],
+            vec!['#', '.', '#', '#', '.', '.', '.', '#', '.', '.', '.'],
+            vec!['#', '.', '.', '.', '#', '#', '.', '.', '.', '.', '#'],
+            vec!['.', '#', '.', '.', '#', '.', '.', '.', '#', '.', '#'],
+        ];
+
+        assert_eq!(trees(&slope, 1, 1), 2);
+        assert_eq!(trees(&slope, 3, 1), 7);
+        assert_eq!(trees(&slope, 5, 1), 3);
+        assert_eq!(trees(&slope, 7, 1), 4);
+        assert_eq!(trees(&slope, 1, 2), 2);
+    }
+}
@@ -0,0 +1,71 @@
+use aoc_core::{parse, Error, Solution};
+
+pub fn sonar_sweep(depths: &[u32], window: usize) -> u32 {
+    if depths.len() <= window {
+        return 0;
+    }
+
+    let windows = depths.windows(window).collect::<Vec<_>>();
+    let increases = windows
+        .iter()
+        .zip(windows[1..].iter())
+        .fold(0, |acc, (m1, m2)| {
+            let sum1: u32 = m1.iter().sum();
+            let sum2: u32 = m2.iter().sum();
+            acc + if sum2 > sum1 { 1 } else { 0 }
+        });
+
+    increases
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 1;
+
+    type Input = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse::lines(input.lines(), |line| parse::value(line, line))?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Error> {
+        Ok(sonar_sweep(input, 1))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Error> {
+        Ok(sonar_sweep(input, 3))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::sonar_sweep;
+
+    #[test]
+    fn test_sonar_sweep_with_zero_depths() {
+        let depths = vec![];
+        assert_eq!(sonar_sweep(&depths, 1), 0);
+    }
+
+    #[test]
+    fn test_sonar_sweep_with_one_depth() {
+        let depths = vec![199];
+        assert_eq!(sonar_sweep(&depths, 1), 0);
+    }
+
+    #[test]
+    fn test_sonar_sweep_with_depths_window_1() {
+        let depths = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        assert_eq!(sonar_sweep(&depths, 1), 7);
+    }
+
+    #[test]
+    fn test_sonar_sweep_with_depths_window_3() {
+        let depths = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        assert_eq!(sonar_sweep(&depths, 3), 5);
+    }
+}
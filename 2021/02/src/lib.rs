@@ -0,0 +1,216 @@
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use aoc_core::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+/// Why a line isn't a valid command.
+#[derive(Error, Debug, PartialEq)]
+pub enum ParseCommandError {
+    #[error("expected forward, down or up")]
+    Direction,
+    #[error("expected a number of units")]
+    Units,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Forward(u32),
+    Down(u32),
+    Up(u32),
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(' ');
+        let direction = parts
+            .next()
+            .ok_or_else(|| ParseError::line(ParseCommandError::Direction, s))?;
+        let units = parts
+            .next()
+            .ok_or_else(|| ParseError::end(ParseCommandError::Units, s))?;
+        let units: u32 = parse::value(s, units)?;
+        match direction {
+            "forward" => Ok(Command::Forward(units)),
+            "down" => Ok(Command::Down(units)),
+            "up" => Ok(Command::Up(units)),
+            _ => Err(ParseError::new(ParseCommandError::Direction, s, direction)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Position {
+    horizontal: u32,
+    depth: u32,
+}
+
+impl Position {
+    pub fn end(&self) -> u32 {
+        self.horizontal * self.depth
+    }
+}
+
+pub fn dive(commands: &[Command]) -> Position {
+    let mut position = Position {
+        horizontal: 0,
+        depth: 0,
+    };
+
+    for command in commands {
+        match command {
+            Command::Forward(x) => position.horizontal += x,
+            Command::Down(x) => position.depth += x,
+            Command::Up(x) => position.depth -= x,
+        }
+    }
+
+    position
+}
+
+pub fn dive_with_aim(commands: &[Command]) -> Position {
+    let mut aim = 0;
+    let mut position = Position {
+        horizontal: 0,
+        depth: 0,
+    };
+
+    for command in commands {
+        match command {
+            Command::Forward(x) => {
+                position.horizontal += x;
+                position.depth += aim * x;
+            }
+            Command::Down(x) => aim += x,
+            Command::Up(x) => aim -= x,
+        }
+    }
+
+    position
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Command>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc_core::Error> {
+        Ok(parse::lines(input.lines(), str::parse)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, aoc_core::Error> {
+        Ok(dive(input).end())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, aoc_core::Error> {
+        Ok(dive_with_aim(input).end())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::num::ParseIntError;
+
+    use aoc_core::parse::ParseError;
+
+    use super::{dive, dive_with_aim, Command, ParseCommandError, Position};
+
+    #[test]
+    fn test_forward_command_from_string() {
+        let given: Result<Command, ParseError> = "forward 5".parse();
+        assert_eq!(given, Ok(Command::Forward(5)));
+    }
+
+    #[test]
+    fn test_down_command_from_string() {
+        let given: Result<Command, ParseError> = "down 5".parse();
+        assert_eq!(given, Ok(Command::Down(5)));
+    }
+
+    #[test]
+    fn test_up_command_from_string() {
+        let given: Result<Command, ParseError> = "up 3".parse();
+        assert_eq!(given, Ok(Command::Up(3)));
+    }
+
+    #[test]
+    fn test_invalid_command_missing_command() {
+        let given = "".parse::<Command>().unwrap_err();
+        assert_eq!(given.reason_is(), Some(&ParseCommandError::Units));
+        assert_eq!(given.columns, 0..0);
+    }
+
+    #[test]
+    fn test_invalid_command_missing_unit() {
+        let given = "forward".parse::<Command>().unwrap_err();
+        assert_eq!(given.reason_is(), Some(&ParseCommandError::Units));
+        assert_eq!(given.columns, 7..7);
+    }
+
+    #[test]
+    fn test_invalid_command_invalid_unit() {
+        let given = "forward a".parse::<Command>().unwrap_err();
+        assert!(given.reason_is::<ParseIntError>().is_some());
+        assert_eq!(given.columns, 8..9);
+    }
+
+    #[test]
+    fn test_invalid_command_unknown_direction() {
+        let given = "backward 2".parse::<Command>().unwrap_err();
+        assert_eq!(given.reason_is(), Some(&ParseCommandError::Direction));
+        assert_eq!(given.columns, 0..8);
+    }
+
+    #[test]
+    fn test_position_end() {
+        let position = Position {
+            horizontal: 15,
+            depth: 10,
+        };
+        assert_eq!(position.end(), 150);
+    }
+
+    #[test]
+    fn test_dive() {
+        let given = vec![
+            Command::Forward(5),
+            Command::Down(5),
+            Command::Forward(8),
+            Command::Up(3),
+            Command::Down(8),
+            Command::Forward(2),
+        ];
+        let expected = Position {
+            horizontal: 15,
+            depth: 10,
+        };
+        assert_eq!(dive(&given), expected);
+    }
+
+    #[test]
+    fn test_dive_with_aim() {
+        let given = vec![
+            Command::Forward(5),
+            Command::Down(5),
+            Command::Forward(8),
+            Command::Up(3),
+            Command::Down(8),
+            Command::Forward(2),
+        ];
+        let expected = Position {
+            horizontal: 15,
+            depth: 60,
+        };
+        assert_eq!(dive_with_aim(&given), expected);
+    }
+}
@@ -0,0 +1,106 @@
+use std::num::ParseIntError;
+
+use aoc_core::{
+    parse::{self, ParseError},
+    Error, Solution,
+};
+
+fn most_common_values(report: &[String]) -> Vec<char> {
+    let length = report.len();
+    let width = report[0].len();
+    let mut columns = vec![0; width];
+
+    for number in report {
+        for (idx, bit) in number.chars().enumerate() {
+            columns[idx] += if bit == '1' { 1 } else { 0 }
+        }
+    }
+
+    let values = columns
+        .iter()
+        .map(|value| if value >= &(length / 2) { '1' } else { '0' })
+        .collect();
+
+    values
+}
+
+fn flip(values: &[char]) -> Vec<char> {
+    values
+        .iter()
+        .map(|value| if *value == '1' { '0' } else { '1' })
+        .collect()
+}
+
+pub fn power_consumption(report: &[String]) -> Result<u32, ParseIntError> {
+    if report.len() == 0 {
+        return Ok(0);
+    }
+
+    let most_common = most_common_values(&report);
+    let epsilon = flip(&most_common).iter().collect::<String>();
+    let gamma = most_common.iter().collect::<String>();
+
+    Ok(u32::from_str_radix(&gamma, 2)? * u32::from_str_radix(&epsilon, 2)?)
+}
+
+pub fn find_rating(report: &[String], criteria: &[char]) -> Result<u32, ParseIntError> {
+    let mut ratings = report.to_vec();
+    let mut candidates = vec![];
+
+    for (idx, value) in criteria.iter().enumerate() {
+        for rating in &ratings {
+            if rating.chars().nth(idx).unwrap() == *value {
+                candidates.push(rating.clone());
+            }
+        }
+
+        if candidates.len() == 1 {
+            return Ok(u32::from_str_radix(&ratings[0].clone(), 2)?);
+        } else {
+            ratings = candidates.clone();
+            candidates.clear();
+        }
+    }
+
+    Ok(42) // XXX This is clearly not right.
+}
+
+pub fn life_support_rating(report: &[String]) -> Result<u32, ParseIntError> {
+    let most_common = most_common_values(&report);
+    let _least_common = flip(&most_common);
+    Ok(0)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(parse::lines(input.lines(), |line| {
+            match line
+                .char_indices()
+                .find(|&(_, bit)| bit != '0' && bit != '1')
+            {
+                Some((at, bit)) => {
+                    let bit = &line[at..at + bit.len_utf8()];
+                    Err(ParseError::new("expected a binary digit", line, bit))
+                }
+                None => Ok(line.to_string()),
+            }
+        })?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Error> {
+        Ok(power_consumption(input)?)
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Self::Part2, Error> {
+        Err(Error::Unimplemented)
+    }
+}
@@ -3,6 +3,8 @@ use std::str::FromStr;
 
 use thiserror::Error;
 
+use aoc_core::{parse::ParseError, Solution};
+
 #[derive(Debug, PartialEq, Error)]
 #[error("invalid bracket: {0}")]
 pub struct InvalidBracket(String);
@@ -11,17 +13,8 @@ pub struct InvalidBracket(String);
 pub enum ParseBracketError {
     Corrupt { expected: Bracket, found: Bracket },
     Incomplete(Vec<Bracket>),
-    Invalid(String),
-}
-
-// XXX: This From impl is written manually to prevent Bracket::try_from
-// returning impossible variants like Corrupt or Incomplete. We just grab the
-// invalid character and pass it up the stack. There's probably a better way to
-// do this, but I don't know what it is...
-impl From<InvalidBracket> for ParseBracketError {
-    fn from(error: InvalidBracket) -> ParseBracketError {
-        ParseBracketError::Invalid(error.0)
-    }
+    // An invalid bracket and the byte it starts at.
+    Invalid(String, usize),
 }
 
 // NOTE: Display is manually implemented for this type because Vec only
@@ -44,7 +37,7 @@ impl fmt::Display for ParseBracketError {
                     .join("");
                 write!(f, "incomplete: '{}'", incomplete)
             }
-            Self::Invalid(bracket) => write!(f, "invalid: {}", bracket),
+            Self::Invalid(bracket, _) => write!(f, "invalid: {}", bracket),
         }
     }
 }
@@ -76,7 +69,7 @@ impl ParseBracketError {
                 }
                 _ => acc,
             }),
-            Self::Invalid(_) => 0,
+            Self::Invalid(..) => 0,
         }
     }
 }
@@ -158,8 +151,11 @@ impl FromStr for Brackets {
         let mut stack: Vec<BracketKind> = vec![];
         let mut brackets: Vec<Bracket> = vec![];
 
-        for chr in string.chars() {
-            let bracket = Bracket::try_from(chr)?;
+        for (at, chr) in string.char_indices() {
+            // Bracket::try_from can't know where the bracket was, so we note
+            // that here rather than converting the error with From.
+            let bracket = Bracket::try_from(chr)
+                .map_err(|InvalidBracket(bracket)| ParseBracketError::Invalid(bracket, at))?;
             match bracket {
                 Bracket::Left(kind) => {
                     stack.push(kind);
@@ -181,7 +177,7 @@ impl FromStr for Brackets {
                     }
                     // The stack was empty, but we found a right bracket. Invalid.
                     None => {
-                        return Err(ParseBracketError::Invalid(chr.to_string()));
+                        return Err(ParseBracketError::Invalid(chr.to_string(), at));
                     }
                 },
             }
@@ -198,9 +194,102 @@ impl FromStr for Brackets {
     }
 }
 
+pub struct Answer {
+    part1: u64,
+    part2: Vec<u64>,
+}
+
+impl Answer {
+    pub fn new() -> Self {
+        Self {
+            part1: 0,
+            part2: vec![],
+        }
+    }
+
+    pub fn part1(&self) -> u64 {
+        self.part1
+    }
+
+    pub fn part2(&mut self) -> Option<&u64> {
+        // Order of equal elements doesn't matter, we don't need a stable sort.
+        self.part2.sort_unstable();
+
+        // It's possible that there are no incomplete brackets, which would
+        // make it unsafe to index the median value, so we return an Option.
+        let mid = self.part2.len() / 2;
+        self.part2.get(mid)
+    }
+
+    // Score a single line (numbered from zero) of the navigation subsystem.
+    pub fn score(&mut self, number: usize, line: &str) -> Result<(), ParseError> {
+        match line.parse::<Brackets>() {
+            // We don't care about valid brackets.
+            Ok(_) => {}
+            // Corruption score counts towards part 1.
+            Err(error @ ParseBracketError::Corrupt { .. }) => {
+                log::debug!("line {}: {}", number + 1, error);
+                self.part1 += error.score();
+            }
+            // Incomplete score counts towards part 2.
+            Err(error @ ParseBracketError::Incomplete(_)) => {
+                log::debug!("line {}: {}", number + 1, error);
+                self.part2.push(error.score());
+            }
+            // Invalid brackets are fatal, so we stop early and point them out.
+            Err(ParseBracketError::Invalid(bracket, at)) => {
+                let offending = &line[at..at + bracket.len()];
+                let error = ParseBracketError::Invalid(bracket, at);
+                return Err(ParseError::new(error, line, offending).on_line(number + 1));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for Answer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 10;
+
+    type Input = Vec<String>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, aoc_core::Error> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, aoc_core::Error> {
+        let mut answer = Answer::new();
+        for (number, line) in input.iter().enumerate() {
+            answer.score(number, line)?;
+        }
+        Ok(answer.part1())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, aoc_core::Error> {
+        let mut answer = Answer::new();
+        for (number, line) in input.iter().enumerate() {
+            answer.score(number, line)?;
+        }
+        answer
+            .part2()
+            .copied()
+            .ok_or(aoc_core::Error::msg("no lines were incomplete"))
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::{Bracket, BracketKind, Brackets, ParseBracketError};
+    use super::{Answer, Bracket, BracketKind, Brackets, ParseBracketError};
 
     #[test]
     fn valid_brackets() {
@@ -275,7 +364,7 @@ mod test {
         let error = "{a}"
             .parse::<Brackets>()
             .expect_err("expected invalid bracket");
-        assert_eq!(error, ParseBracketError::Invalid("a".to_string()));
+        assert_eq!(error, ParseBracketError::Invalid("a".to_string(), 1));
     }
 
     #[test]
@@ -283,6 +372,16 @@ mod test {
         let error = "}"
             .parse::<Brackets>()
             .expect_err("expected invalid bracket");
-        assert_eq!(error, ParseBracketError::Invalid("}".to_string()));
+        assert_eq!(error, ParseBracketError::Invalid("}".to_string(), 0));
+    }
+
+    #[test]
+    fn invalid_bracket_location() {
+        let mut answer = Answer::new();
+        let error = answer
+            .score(2, "[(a)]")
+            .expect_err("expected invalid bracket");
+        assert_eq!(error.line, 3);
+        assert_eq!(error.columns, 2..3);
     }
 }
@@ -0,0 +1,96 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use aoc_core::{
+    parse::{self, ParseError},
+    Error, Solution,
+};
+
+pub fn top_calories(
+    lines: impl IntoIterator<Item = impl AsRef<str>>,
+    top: usize,
+) -> Result<Vec<u64>, ParseError> {
+    fn push_elf(elves: &mut BinaryHeap<Reverse<u64>>, calories: &mut Vec<u64>, top: usize) {
+        elves.push(Reverse(calories.iter().sum()));
+        if elves.len() > top {
+            elves.pop();
+        }
+        calories.clear();
+    }
+
+    // Use a min heap to track elf calories. By popping all of the smallest
+    // values we've seen so far we can retain only the `top` largest values. We
+    // use a capacity of `top + 1` to allow for one comparison before popping.
+    let mut elves = BinaryHeap::with_capacity(top + 1);
+    let mut calories: Vec<u64> = vec![];
+
+    for (number, line) in lines.into_iter().enumerate() {
+        let line = line.as_ref();
+        if line.is_empty() {
+            // The line is empty, so we must have just finished gathering all
+            // the calories for one elf. Push their calories into the heap.
+            push_elf(&mut elves, &mut calories, top);
+        } else {
+            // Gather up calories for the current elf.
+            let item = parse::value(line, line).map_err(|error| error.on_line(number + 1))?;
+            calories.push(item);
+        }
+    }
+
+    // Push the final elf into the heap as their calories won't be separated by
+    // an empty line.
+    push_elf(&mut elves, &mut calories, top);
+
+    Ok(elves.into_sorted_vec().into_iter().map(|r| r.0).collect())
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Error> {
+        Ok(top_calories(input, 1)?.iter().sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Error> {
+        Ok(top_calories(input, 3)?.iter().sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::top_calories;
+    use aoc_core::parse::ParseError;
+
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    const ELVEN_CALORIES: [&str; 14] = [
+        "1000", "2000", "3000", "", "4000", "", "5000", "6000", "", "7000", "8000", "9000", "",
+        "10000",
+    ];
+
+    #[test_case(24_000, 1 ; "part1")]
+    #[test_case(45_000, 3 ; "part2")]
+    fn test_top_calories(expected: u64, num_elves: usize) -> Result<(), ParseError> {
+        let answer: u64 = top_calories(ELVEN_CALORIES, num_elves)?.iter().sum();
+        assert_eq!(answer, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_calories() {
+        let error = top_calories(["1000", "", "2OOO"], 1).unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.offending(), "2OOO");
+    }
+}
@@ -1,91 +1,34 @@
-use std::{
-    cmp::Reverse,
-    collections::BinaryHeap,
-    fs::File,
-    io::{BufRead, BufReader},
-    num::ParseIntError,
-    path::PathBuf,
-};
+use std::process::ExitCode;
 
+use aoc_core::{
+    cli::{self, Options},
+    report::Record,
+    Solution,
+};
 use clap::Parser;
 
+use aoc_2022_01::{top_calories, Puzzle};
+
 #[derive(Debug, Parser)]
 struct Args {
-    input: PathBuf,
+    #[command(flatten)]
+    options: Options,
     #[clap(long, default_value_t = 3)]
     num_elves: usize,
 }
 
-fn top_calories(
-    lines: impl IntoIterator<Item = impl AsRef<str>>,
-    top: usize,
-) -> Result<Vec<u64>, ParseIntError> {
-    fn push_elf(elves: &mut BinaryHeap<Reverse<u64>>, calories: &mut Vec<u64>, top: usize) {
-        elves.push(Reverse(calories.iter().sum()));
-        if elves.len() > top {
-            elves.pop();
-        }
-        calories.clear();
-    }
-
-    // Use a min heap to track elf calories. By popping all of the smallest
-    // values we've seen so far we can retain only the `top` largest values. We
-    // use a capacity of `top + 1` to allow for one comparison before popping.
-    let mut elves = BinaryHeap::with_capacity(top + 1);
-    let mut calories: Vec<u64> = vec![];
-
-    for line in lines {
-        let line = line.as_ref();
-        if line.is_empty() {
-            // The line is empty, so we must have just finished gathering all
-            // the calories for one elf. Push their calories into the heap.
-            push_elf(&mut elves, &mut calories, top);
-        } else {
-            // Gather up calories for the current elf.
-            let item = line.parse()?;
-            calories.push(item);
-        }
-    }
-
-    // Push the final elf into the heap as their calories won't be separated by
-    // an empty line.
-    push_elf(&mut elves, &mut calories, top);
-
-    Ok(elves.into_sorted_vec().into_iter().map(|r| r.0).collect())
-}
-
-fn main() -> anyhow::Result<()> {
+fn main() -> ExitCode {
     let args = Args::parse();
-    let reader = BufReader::new(File::open(args.input)?);
-    let lines = reader.lines().collect::<Result<Vec<String>, _>>()?;
-
-    let calories = top_calories(lines, args.num_elves)?;
-    let part1 = calories[0];
-    let part2: u64 = calories.iter().sum();
-
-    println!("Part 1: {part1}\nPart 2: {part2}");
-
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use super::top_calories;
-    use std::num::ParseIntError;
-
-    use pretty_assertions::assert_eq;
-    use test_case::test_case;
-
-    const ELVEN_CALORIES: [&str; 14] = [
-        "1000", "2000", "3000", "", "4000", "", "5000", "6000", "", "7000", "8000", "9000", "",
-        "10000",
-    ];
-
-    #[test_case(24_000, 1 ; "part1")]
-    #[test_case(45_000, 3 ; "part2")]
-    fn test_top_calories(expected: u64, num_elves: usize) -> Result<(), ParseIntError> {
-        let answer: u64 = top_calories(ELVEN_CALORIES, num_elves)?.iter().sum();
-        assert_eq!(answer, expected);
-        Ok(())
-    }
+    let records = match args.options.source().read() {
+        Ok(input) => {
+            let lines = input.lines().collect::<Vec<_>>();
+            [(1, 1), (2, args.num_elves)].map(|(part, top)| {
+                Record::timed(Puzzle::YEAR, Puzzle::DAY, part, || {
+                    Ok(top_calories(&lines, top)?.iter().sum::<u64>())
+                })
+            })
+        }
+        Err(error) => Record::failed(Puzzle::YEAR, Puzzle::DAY, error),
+    };
+    cli::print(args.options.format, &records)
 }
@@ -1,3 +1,5 @@
+use aoc_core::parse::{self, ParseError};
+
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum Error {
     #[error("The line was missing a digit")]
@@ -18,17 +20,16 @@ fn calibrate(line: impl AsRef<str>) -> Result<u32, Error> {
 }
 
 pub fn solve(lines: impl IntoIterator<Item = impl AsRef<str>>) -> anyhow::Result<u32> {
-    Ok(lines
-        .into_iter()
-        .map(calibrate)
-        .collect::<Result<Vec<u32>, Error>>()?
-        .iter()
-        .sum())
+    let calibrations = parse::lines(lines, |line| {
+        calibrate(line).map_err(|error| ParseError::line(error, line))
+    })?;
+    Ok(calibrations.iter().sum())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{calibrate, Error};
+    use super::{calibrate, solve, Error};
+    use aoc_core::parse::ParseError;
     use pretty_assertions::assert_eq;
     use test_case::test_case;
 
@@ -49,4 +50,19 @@ mod tests {
     fn missing_digit(line: &str) {
         assert_eq!(calibrate(line), Err(Error::MissingDigit))
     }
+
+    #[test]
+    fn missing_digit_line() {
+        let error = solve(["1abc2", "abc"]).unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.reason_is(), Some(&Error::MissingDigit));
+    }
+
+    #[test]
+    fn solution() -> anyhow::Result<()> {
+        let actual = solve(aoc_core::example!(0).lines())?;
+        assert_eq!(actual, 142);
+        Ok(())
+    }
 }
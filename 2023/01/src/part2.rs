@@ -1,4 +1,5 @@
 use aho_corasick::AhoCorasick;
+use aoc_core::parse::{self, ParseError};
 
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum Error {
@@ -55,17 +56,17 @@ impl<'a> Calibrator {
 
 pub fn solve(lines: impl IntoIterator<Item = impl AsRef<str>>) -> anyhow::Result<u32> {
     let calibrator = Calibrator::new()?;
-    Ok(lines
-        .into_iter()
-        .map(|line| calibrator.calibrate(line))
-        .collect::<Result<Vec<u32>, Error>>()?
-        .iter()
-        .sum())
+    let calibrations = parse::lines(lines, |line| {
+        calibrator
+            .calibrate(line)
+            .map_err(|error| ParseError::line(error, line))
+    })?;
+    Ok(calibrations.iter().sum())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Calibrator, Error};
+    use super::{solve, Calibrator, Error};
     use pretty_assertions::assert_eq;
     use test_case::test_case;
 
@@ -94,4 +95,11 @@ mod tests {
         assert!(matches!(actual, Err(Error::MissingDigit)));
         Ok(())
     }
+
+    #[test]
+    fn solution() -> anyhow::Result<()> {
+        let actual = solve(aoc_core::example!(1).lines())?;
+        assert_eq!(actual, 281);
+        Ok(())
+    }
 }
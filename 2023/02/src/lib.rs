@@ -0,0 +1,263 @@
+use std::str::FromStr;
+
+use aoc_core::{
+    parse::{self, ParseError},
+    Error, Solution,
+};
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseCubeError {
+    #[error("Expected a count and a color, like '3 blue'")]
+    Cube,
+    #[error("Expected red, green or blue")]
+    Color,
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ParseGameError {
+    #[error("Expected a game, like 'Game 1: 3 blue'")]
+    Game,
+    #[error("Expected 'Game'")]
+    Label,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Cube {
+    Red(u32),
+    Green(u32),
+    Blue(u32),
+}
+
+impl Cube {
+    /// Parse a cube from part of a line, blaming that part of the line if it
+    /// isn't a cube.
+    fn parse_within(line: &str, input: &str) -> Result<Self, ParseError> {
+        let Some((count, color)) = input.split_once(' ') else {
+            return Err(ParseError::new(ParseCubeError::Cube, line, input));
+        };
+        let count: u32 = parse::value(line, count)?;
+
+        match color {
+            "red" => Ok(Self::Red(count)),
+            "green" => Ok(Self::Green(count)),
+            "blue" => Ok(Self::Blue(count)),
+            _ => Err(ParseError::new(ParseCubeError::Color, line, color)),
+        }
+    }
+}
+
+impl FromStr for Cube {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse_within(input, input)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    id: u32,
+    sets: Vec<Vec<Cube>>,
+}
+
+impl Game {
+    pub fn possible(&self, red: u32, green: u32, blue: u32) -> bool {
+        for set in &self.sets {
+            for cube in set {
+                match cube {
+                    Cube::Red(count) => {
+                        if *count > red {
+                            return false;
+                        }
+                    }
+                    Cube::Green(count) => {
+                        if *count > green {
+                            return false;
+                        }
+                    }
+                    Cube::Blue(count) => {
+                        if *count > blue {
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let Some((game, sets)) = input.split_once(": ") else {
+            return Err(ParseError::line(ParseGameError::Game, input));
+        };
+
+        let Some((label, id)) = game.split_once(' ') else {
+            return Err(ParseError::new(ParseGameError::Game, input, game));
+        };
+        if label != "Game" {
+            return Err(ParseError::new(ParseGameError::Label, input, label));
+        }
+        let id: u32 = parse::value(input, id)?;
+
+        let sets = sets
+            .split("; ")
+            .map(|s| {
+                s.split(", ")
+                    .map(|c| Cube::parse_within(input, c))
+                    .collect::<Result<Vec<Cube>, _>>()
+            })
+            .collect::<Result<Vec<Vec<Cube>>, _>>()?;
+
+        Ok(Game { id, sets })
+    }
+}
+
+pub fn part1(
+    lines: impl IntoIterator<Item = impl AsRef<str>>,
+    red: u32,
+    green: u32,
+    blue: u32,
+) -> anyhow::Result<u32> {
+    let mut sum = 0;
+    for game in parse::lines(lines, str::parse::<Game>)? {
+        if game.possible(red, green, blue) {
+            sum += game.id;
+        }
+    }
+    Ok(sum)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Error> {
+        Ok(part1(input, 12, 13, 14)?)
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Self::Part2, Error> {
+        Err(Error::Unimplemented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::ParseIntError;
+
+    use aoc_core::parse::ParseError;
+
+    use super::{part1, Cube::*, Game, ParseCubeError, ParseGameError};
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    #[test_case(
+        0,
+        Game { id: 1, sets: vec![vec![Blue(3), Red(4)], vec![Red(1), Green(2), Blue(6)], vec![Green(2)]]}
+        ; "Game 1"
+    )]
+    #[test_case(
+        1,
+        Game { id: 2, sets: vec![vec![Blue(1), Green(2)], vec![Green(3), Blue(4), Red(1)], vec![Green(1), Blue(1)]]}
+        ; "Game 2"
+    )]
+    #[test_case(
+        2,
+        Game { id: 3, sets: vec![vec![Green(8), Blue(6), Red(20)], vec![Blue(5), Red(4), Green(13)], vec![Green(5), Red(1)]]}
+        ; "Game 3"
+    )]
+    #[test_case(
+        3,
+        Game { id: 4, sets: vec![vec![Green(1), Red(3), Blue(6)], vec![Green(3), Red(6)], vec![Green(3), Blue(15), Red(14)]]}
+        ; "Game 4"
+    )]
+    #[test_case(
+        4,
+        Game { id: 5, sets: vec![vec![Red(6), Blue(1), Green(3)], vec![Blue(2), Red(1), Green(2)]]}
+        ; "Game 5"
+    )]
+    // Each game is a line of the README's example, counting from 0.
+    fn game_parsing(line: usize, expected: Game) -> anyhow::Result<()> {
+        let record = aoc_core::example!(0)
+            .lines()
+            .nth(line)
+            .unwrap_or_else(|| panic!("README example 0 has no line {line}"));
+        let actual: Game = record.parse()?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test_case("Game 1 3 blue", 0..13, ParseGameError::Game ; "missing colon")]
+    #[test_case("Game: 3 blue", 0..4, ParseGameError::Game ; "missing ID")]
+    #[test_case("Gmae 1: 3 blue", 0..4, ParseGameError::Label ; "misspelled")]
+    fn game_errors(record: &str, columns: std::ops::Range<usize>, reason: ParseGameError) {
+        let error = record.parse::<Game>().unwrap_err();
+        assert_eq!(error.columns, columns);
+        assert_eq!(error.reason_is(), Some(&reason));
+    }
+
+    #[test_case("Game 1: 3 blue; 4", 16..17, ParseCubeError::Cube ; "missing color")]
+    #[test_case("Game 1: 3 blue, 4 pink", 18..22, ParseCubeError::Color ; "unknown color")]
+    fn cube_errors(record: &str, columns: std::ops::Range<usize>, reason: ParseCubeError) {
+        let error = record.parse::<Game>().unwrap_err();
+        assert_eq!(error.columns, columns);
+        assert_eq!(error.reason_is(), Some(&reason));
+    }
+
+    #[test]
+    fn count_error() {
+        let error = part1(["Game 1: 3 blue", "Game 2: x red"], 12, 13, 14).unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((error.line, error.columns.clone()), (2, 8..9));
+        assert!(error.reason_is::<ParseIntError>().is_some());
+    }
+
+    #[test_case(
+        Game { id: 1, sets: vec![vec![Blue(3), Red(4)], vec![Red(1), Green(2), Blue(6)], vec![Green(2)]]}
+        ; "Game 1"
+    )]
+    #[test_case(
+        Game { id: 2, sets: vec![vec![Blue(1), Green(2)], vec![Green(3), Blue(4), Red(1)], vec![Green(1), Blue(1)]]}
+        ; "Game 2"
+    )]
+    #[test_case(
+        Game { id: 5, sets: vec![vec![Red(6), Blue(1), Green(3)], vec![Blue(2), Red(1), Green(2)]]}
+        ; "Game 5"
+    )]
+    fn possible(game: Game) {
+        assert!(game.possible(12, 13, 14));
+    }
+
+    #[test_case(
+        Game { id: 3, sets: vec![vec![Green(8), Blue(6), Red(20)], vec![Blue(5), Red(4), Green(13)], vec![Green(5), Red(1)]]}
+        ; "Game 3"
+    )]
+    #[test_case(
+        Game { id: 4, sets: vec![vec![Green(1), Red(3), Blue(6)], vec![Green(3), Red(6)], vec![Green(3), Blue(15), Red(14)]]}
+        ; "Game 4"
+    )]
+    fn impossible(game: Game) {
+        assert!(!game.possible(12, 13, 14));
+    }
+
+    #[test]
+    fn solution() -> anyhow::Result<()> {
+        let actual = part1(aoc_core::example!(0).lines(), 12, 13, 14)?;
+        assert_eq!(actual, 8);
+        Ok(())
+    }
+}
@@ -1,15 +1,21 @@
+use aoc_core::parse::{self, ParseError};
+
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum Error {
     #[error("The line was missing a location ID")]
     MissingLocationId,
-    #[error(transparent)]
-    Parse(#[from] std::num::ParseIntError),
 }
 
-pub fn parse(line: impl AsRef<str>) -> Result<(u32, u32), Error> {
-    let mut parts = line.as_ref().split_whitespace();
-    let left: u32 = parts.next().ok_or(Error::MissingLocationId)?.parse()?;
-    let right: u32 = parts.next().ok_or(Error::MissingLocationId)?.parse()?;
+pub fn parse(line: &str) -> Result<(u32, u32), ParseError> {
+    let mut parts = line.split_whitespace();
+    let mut location_id = || {
+        let part = parts
+            .next()
+            .ok_or_else(|| ParseError::end(Error::MissingLocationId, line))?;
+        parse::value(line, part)
+    };
+    let left: u32 = location_id()?;
+    let right: u32 = location_id()?;
     Ok((left, right))
 }
 
@@ -34,19 +40,20 @@ mod tests {
         Ok(())
     }
 
-    #[test_case("" ; "empty")]
-    #[test_case("42   " ; "missing left")]
-    #[test_case("   42" ; "missing right")]
-    fn missing_location_id(line: &str) {
-        assert_eq!(parse(line), Err(Error::MissingLocationId));
+    #[test_case("", 0 ; "empty")]
+    #[test_case("42   ", 5 ; "missing left")]
+    #[test_case("   42", 5 ; "missing right")]
+    fn missing_location_id(line: &str, end: usize) {
+        let error = parse(line).unwrap_err();
+        assert_eq!(error.reason_is(), Some(&Error::MissingLocationId));
+        assert_eq!(error.columns, end..end);
     }
 
-    #[test_case("a   42" ; "invalid left")]
-    #[test_case("42   a" ; "invalid right")]
-    fn invalid_location_id(line: &str) {
-        assert!(matches!(
-            parse(line),
-            Err(Error::Parse(ParseIntError { .. }))
-        ));
+    #[test_case("a   42", 0..1 ; "invalid left")]
+    #[test_case("42   a", 5..6 ; "invalid right")]
+    fn invalid_location_id(line: &str, columns: std::ops::Range<usize>) {
+        let error = parse(line).unwrap_err();
+        assert!(error.reason_is::<ParseIntError>().is_some());
+        assert_eq!(error.columns, columns);
     }
 }
@@ -1,13 +1,10 @@
+use aoc_core::parse;
+
 use crate::pair;
 
 pub fn solve(lines: impl IntoIterator<Item = impl AsRef<str>>) -> anyhow::Result<u32> {
-    let (mut left, mut right): (Vec<_>, Vec<_>) = lines
-        .into_iter()
-        .map(pair::parse)
-        .collect::<Result<Vec<(u32, u32)>, _>>()?
-        .iter()
-        .cloned()
-        .unzip();
+    let (mut left, mut right): (Vec<_>, Vec<_>) =
+        parse::lines(lines, pair::parse)?.into_iter().unzip();
 
     left.sort();
     right.sort();
@@ -28,7 +25,7 @@ mod tests {
     #[test]
     fn solution() -> anyhow::Result<()> {
         let expected = 11;
-        let puzzle = ["3   4", "4   3", "2   5", "1   3", "3   9", "3   3"];
+        let puzzle = aoc_core::example!(0).lines();
         let actual = solve(puzzle)?;
         assert_eq!(actual, expected);
         Ok(())
@@ -1,15 +1,11 @@
 use std::collections::HashMap;
 
+use aoc_core::parse;
+
 use crate::pair;
 
 pub fn solve(lines: impl IntoIterator<Item = impl AsRef<str>>) -> anyhow::Result<u32> {
-    let (left, right): (Vec<_>, Vec<_>) = lines
-        .into_iter()
-        .map(pair::parse)
-        .collect::<Result<Vec<(u32, u32)>, _>>()?
-        .iter()
-        .cloned()
-        .unzip();
+    let (left, right): (Vec<_>, Vec<_>) = parse::lines(lines, pair::parse)?.into_iter().unzip();
 
     let mut map: HashMap<u32, u32> = HashMap::new();
     for value in right {
@@ -28,7 +24,7 @@ mod tests {
     #[test]
     fn solution() -> anyhow::Result<()> {
         let expected = 31;
-        let puzzle = ["3   4", "4   3", "2   5", "1   3", "3   9", "3   3"];
+        let puzzle = aoc_core::example!(1).lines();
         let actual = solve(puzzle)?;
         assert_eq!(actual, expected);
         Ok(())
@@ -0,0 +1,46 @@
+use std::path::Path;
+
+use aoc::days::DAYS;
+use aoc_core::Error;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+// Time parsing and each part separately for every day against its input.txt.
+// Benchmarks are named like "2021/01/part1" so they can be filtered with
+// `cargo bench -- 2021/01`.
+fn days(c: &mut Criterion) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    for day in DAYS {
+        let name = day.name();
+        let input = match day.input(&root) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Skipping {name}: {error}");
+                continue;
+            }
+        };
+        let parsed = match (day.parse)(&input) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                eprintln!("Skipping {name}: {error}");
+                continue;
+            }
+        };
+
+        let mut group = c.benchmark_group(&name);
+        group.bench_function("parse", |b| b.iter(|| (day.parse)(&input)));
+        for (part, solve) in [("part1", day.part1), ("part2", day.part2)] {
+            match solve(&parsed) {
+                Ok(_) => group.bench_function(part, |b| b.iter(|| solve(&parsed))),
+                Err(Error::Unimplemented) => continue,
+                Err(error) => {
+                    eprintln!("Skipping {name} {part}: {error}");
+                    continue;
+                }
+            };
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,96 @@
+use std::{io, process::ExitCode};
+
+use clap::Parser;
+
+use crate::{
+    input::Source,
+    report::{self, Format, Record, Writer},
+    Solution,
+};
+
+/// Arguments shared by every day's binary. Days with options of their own can
+/// flatten these into their own arguments.
+#[derive(Debug, clap::Args)]
+pub struct Options {
+    /// The puzzle input file, or - to read it from stdin
+    #[arg(default_value = "-")]
+    pub input: Source,
+    /// The puzzle input itself, instead of a file
+    #[arg(long, conflicts_with = "input")]
+    pub text: Option<String>,
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+}
+
+impl Options {
+    /// Where to read the puzzle input from.
+    pub fn source(&self) -> Source {
+        match &self.text {
+            Some(text) => Source::Inline(text.clone()),
+            None => self.input.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(flatten)]
+    options: Options,
+}
+
+/// Solve a day's puzzle from the command line, printing the answer to each
+/// part in the requested format.
+pub fn main<S: Solution>() -> ExitCode {
+    let Args { options } = Args::parse();
+    let records = match options.source().read() {
+        Ok(input) => report::records::<S>(&input),
+        Err(error) => Record::failed(S::YEAR, S::DAY, error),
+    };
+    print(options.format, &records)
+}
+
+/// Print records to stdout, failing if any part failed.
+pub fn print(format: Format, records: &[Record]) -> ExitCode {
+    let mut writer = Writer::new(format, io::stdout().lock());
+    let written = records
+        .iter()
+        .try_for_each(|record| writer.write(record))
+        .and_then(|()| Ok(writer.flush()?));
+    if let Err(error) = written {
+        eprintln!("Error: {error:#}");
+        return ExitCode::FAILURE;
+    }
+
+    if records.iter().any(|record| record.error.is_some()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::Args;
+    use crate::input::Source;
+
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    #[test_case(&[], Source::Stdin ; "stdin by default")]
+    #[test_case(&["-"], Source::Stdin ; "stdin")]
+    #[test_case(&["input.txt"], Source::Path("input.txt".into()) ; "path")]
+    #[test_case(&["--text", "1 2"], Source::Inline("1 2".into()) ; "inline")]
+    fn sources(args: &[&str], expected: Source) -> anyhow::Result<()> {
+        let args = Args::try_parse_from(["aoc"].iter().chain(args))?;
+        assert_eq!(args.options.source(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn path_and_text() {
+        assert!(Args::try_parse_from(["aoc", "input.txt", "--text", "1 2"]).is_err());
+    }
+}
@@ -0,0 +1,101 @@
+/// Extract the contents of every fenced code block in a day's README.md, in
+/// the order they appear. The fences themselves and the final newline of each
+/// block aren't included.
+pub fn extract(markdown: &str) -> Vec<&str> {
+    let mut examples = vec![];
+    let mut start = None;
+    let mut offset = 0;
+
+    for line in markdown.split_inclusive('\n') {
+        if line.starts_with("```") || line.starts_with("~~~") {
+            match start.take() {
+                Some(start) => {
+                    let example = &markdown[start..offset];
+                    examples.push(example.strip_suffix('\n').unwrap_or(example));
+                }
+                None => start = Some(offset + line.len()),
+            }
+        }
+        offset += line.len();
+    }
+
+    examples
+}
+
+/// Get a single example from a day's README.md, counting from 0.
+///
+/// # Panics
+///
+/// If the README.md doesn't have that many examples. This is meant for
+/// loading fixtures in tests, where that's a mistake in the test itself.
+pub fn nth(markdown: &str, n: usize) -> &str {
+    let examples = extract(markdown);
+    examples.get(n).copied().unwrap_or_else(|| {
+        panic!(
+            "README.md has {} example(s), so there's no example {n}",
+            examples.len()
+        )
+    })
+}
+
+/// Load an example from the README.md of the crate being compiled, counting
+/// from 0. See [`examples::nth`](crate::examples::nth).
+///
+/// ```ignore
+/// let puzzle = aoc_core::example!(0).lines();
+/// ```
+#[macro_export]
+macro_rules! example {
+    ($n:expr) => {
+        $crate::examples::nth(
+            include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md")),
+            $n,
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extract, nth};
+
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    const README: &str = "\
+# Day 1: Example
+
+For example:
+
+```
+1
+2
+```
+
+And also:
+
+```text
+3
+```
+";
+
+    #[test_case("", vec![] ; "empty")]
+    #[test_case("No examples here.\n", vec![] ; "no examples")]
+    #[test_case(README, vec!["1\n2", "3"] ; "examples")]
+    #[test_case("```\n```\n", vec![""] ; "empty example")]
+    #[test_case("~~~\n1\n~~~", vec!["1"] ; "tildes without a final newline")]
+    #[test_case("```\n1\n", vec![] ; "unclosed")]
+    fn extracting(markdown: &str, expected: Vec<&str>) {
+        assert_eq!(extract(markdown), expected);
+    }
+
+    #[test]
+    fn nth_example() {
+        assert_eq!(nth(README, 1), "3");
+    }
+
+    #[test]
+    #[should_panic(expected = "README.md has 2 example(s), so there's no example 2")]
+    fn missing_example() {
+        nth(README, 2);
+    }
+}
@@ -0,0 +1,113 @@
+use std::{
+    convert::Infallible,
+    fmt, fs,
+    io::{self, Read},
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// Where a puzzle's input comes from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    Stdin,
+    Path(PathBuf),
+    Inline(String),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to read {}: {source}", path.display())]
+    Path { path: PathBuf, source: io::Error },
+    #[error("Failed to read stdin: {0}")]
+    Stdin(#[source] io::Error),
+}
+
+impl Source {
+    /// Read the whole input.
+    pub fn read(&self) -> Result<String, Error> {
+        self.read_with(io::stdin().lock())
+    }
+
+    /// Read the whole input, using the given reader in place of stdin.
+    pub fn read_with(&self, mut stdin: impl Read) -> Result<String, Error> {
+        match self {
+            Self::Stdin => {
+                let mut input = String::new();
+                stdin.read_to_string(&mut input).map_err(Error::Stdin)?;
+                Ok(input)
+            }
+            Self::Path(path) => fs::read_to_string(path).map_err(|source| Error::Path {
+                path: path.clone(),
+                source,
+            }),
+            Self::Inline(input) => Ok(input.clone()),
+        }
+    }
+}
+
+/// A path to read from, where "-" means stdin.
+impl FromStr for Source {
+    type Err = Infallible;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "-" => Ok(Self::Stdin),
+            path => Ok(Self::Path(path.into())),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stdin => write!(f, "stdin"),
+            Self::Path(path) => write!(f, "{}", path.display()),
+            Self::Inline(_) => write!(f, "inline input"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::{Error, Source};
+
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    #[test_case("-", Source::Stdin ; "stdin")]
+    #[test_case("input.txt", Source::Path("input.txt".into()) ; "path")]
+    fn parsing(input: &str, expected: Source) {
+        assert_eq!(input.parse(), Ok(expected));
+    }
+
+    #[test_case(Source::Stdin, "from stdin" ; "stdin")]
+    #[test_case(Source::Inline("1\n2\n".into()), "1\n2\n" ; "inline")]
+    #[test_case(
+        Source::Path(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml").into()),
+        include_str!("../Cargo.toml")
+        ; "path"
+    )]
+    fn reading(source: Source, expected: &str) -> anyhow::Result<()> {
+        let actual = source.read_with("from stdin".as_bytes())?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn missing_path() {
+        let source = Source::Path("missing.txt".into());
+        let error = source.read_with(io::empty()).unwrap_err();
+        assert!(matches!(error, Error::Path { .. }));
+        assert!(error
+            .to_string()
+            .starts_with("Failed to read missing.txt: "));
+    }
+
+    #[test]
+    fn invalid_stdin() {
+        let error = Source::Stdin.read_with(&[0xff, 0xfe][..]).unwrap_err();
+        assert!(matches!(error, Error::Stdin(_)));
+    }
+}
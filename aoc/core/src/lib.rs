@@ -0,0 +1,147 @@
+pub mod cli;
+pub mod examples;
+pub mod input;
+pub mod parse;
+pub mod report;
+
+use std::fmt;
+
+/// A solution to both parts of a single day's puzzle.
+///
+/// The puzzle input is parsed once and then shared by both parts, which lets
+/// tooling like the `aoc` runner treat every day the same way.
+pub trait Solution {
+    /// The year the puzzle is from.
+    const YEAR: u16;
+    /// The day the puzzle is from.
+    const DAY: u8;
+
+    /// The parsed puzzle input, shared by both parts.
+    type Input;
+    /// The answer to part 1.
+    type Part1: fmt::Display;
+    /// The answer to part 2.
+    type Part2: fmt::Display;
+
+    fn parse(input: &str) -> Result<Self::Input, Error>;
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Error>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Error>;
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// This part of the puzzle hasn't been solved yet.
+    Unimplemented,
+    /// Anything else that went wrong while parsing or solving.
+    Failed(anyhow::Error),
+}
+
+impl Error {
+    /// Create an error from a message, like [`anyhow::Error::msg`].
+    pub fn msg<M>(message: M) -> Self
+    where
+        M: fmt::Display + fmt::Debug + Send + Sync + 'static,
+    {
+        Self::Failed(anyhow::Error::msg(message))
+    }
+}
+
+// NOTE: Error deliberately doesn't implement std::error::Error so that this
+// blanket impl doesn't conflict with From<T> for T. This is the same trick
+// anyhow uses and it means ? works for anything anyhow can hold.
+impl<E> From<E> for Error
+where
+    E: Into<anyhow::Error>,
+{
+    fn from(error: E) -> Self {
+        Self::Failed(error.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unimplemented => write!(f, "not implemented"),
+            Self::Failed(error) => write!(f, "{error:#}"),
+        }
+    }
+}
+
+/// The answer to one part of a puzzle, rendered for display.
+pub type Answer = Result<String, Error>;
+
+/// The answers to both parts of a puzzle.
+#[derive(Debug)]
+pub struct Answers {
+    pub part1: Answer,
+    pub part2: Answer,
+}
+
+/// Parse the puzzle input once and then solve both parts with it. Only a
+/// failure to parse is an error, each part succeeds or fails on its own.
+pub fn solve<S: Solution>(input: &str) -> Result<Answers, Error> {
+    let input = S::parse(input)?;
+    Ok(Answers {
+        part1: S::part1(&input).map(|answer| answer.to_string()),
+        part2: S::part2(&input).map(|answer| answer.to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{solve, Error, Solution};
+
+    use pretty_assertions::assert_eq;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 1;
+
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input, Error> {
+            Ok(input
+                .lines()
+                .map(str::parse)
+                .collect::<Result<Vec<_>, _>>()?)
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Part1, Error> {
+            input
+                .iter()
+                .sum::<u32>()
+                .checked_sub(1)
+                .ok_or(Error::msg("underflow"))
+        }
+
+        fn part2(_input: &Self::Input) -> Result<Self::Part2, Error> {
+            Err(Error::Unimplemented)
+        }
+    }
+
+    #[test]
+    fn solving() {
+        let answers = solve::<Sum>("1\n2\n3").expect("expected valid input");
+        assert_eq!(answers.part1.ok(), Some("5".to_string()));
+        assert!(matches!(answers.part2, Err(Error::Unimplemented)));
+    }
+
+    #[test]
+    fn failing_part() {
+        let answers = solve::<Sum>("0").expect("expected valid input");
+        assert_eq!(
+            answers.part1.map_err(|e| e.to_string()),
+            Err("underflow".to_string())
+        );
+    }
+
+    #[test]
+    fn failing_parse() {
+        let error = solve::<Sum>("a").expect_err("expected invalid input");
+        assert_eq!(error.to_string(), "invalid digit found in string");
+    }
+}
@@ -0,0 +1,213 @@
+use std::{error::Error, fmt, ops::Range, str::FromStr};
+
+type Reason = Box<dyn Error + Send + Sync + 'static>;
+
+/// A failure to parse the puzzle input which points at the offending text,
+/// rendered like a rustc diagnostic.
+///
+/// ```text
+/// invalid digit found in string
+///  --> 2:6
+///   |
+/// 2 | Game x: 3 blue
+///   |      ^
+/// ```
+#[derive(Debug)]
+pub struct ParseError {
+    reason: Reason,
+    /// The line of the input the error is on, counting from 1.
+    pub line: usize,
+    /// The characters of the line which couldn't be parsed, counting from 0.
+    pub columns: Range<usize>,
+    /// The whole line the error is on.
+    pub text: String,
+}
+
+impl ParseError {
+    /// An error with part of a line. If `offending` isn't a slice of `text`
+    /// then the whole line is blamed.
+    ///
+    /// The line number is 1 until it's set with [`ParseError::on_line`], so
+    /// parsers which only ever see a single line needn't know where it is.
+    pub fn new(reason: impl Into<Reason>, text: &str, offending: &str) -> Self {
+        let columns = match offset(text, offending) {
+            Some(start) => {
+                let start = text[..start].chars().count();
+                start..start + offending.chars().count()
+            }
+            None => 0..text.chars().count(),
+        };
+        Self {
+            reason: reason.into(),
+            line: 1,
+            columns,
+            text: text.into(),
+        }
+    }
+
+    /// An error with a whole line.
+    pub fn line(reason: impl Into<Reason>, text: &str) -> Self {
+        Self::new(reason, text, text)
+    }
+
+    /// An error with something missing from the end of a line.
+    pub fn end(reason: impl Into<Reason>, text: &str) -> Self {
+        Self::new(reason, text, &text[text.len()..])
+    }
+
+    /// Set the line of the input the error is on, counting from 1.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Why the text couldn't be parsed.
+    pub fn reason(&self) -> &(dyn Error + Send + Sync + 'static) {
+        self.reason.as_ref()
+    }
+
+    /// Why the text couldn't be parsed, if it was for a particular reason.
+    pub fn reason_is<E: Error + 'static>(&self) -> Option<&E> {
+        self.reason.downcast_ref()
+    }
+
+    /// The text which couldn't be parsed.
+    pub fn offending(&self) -> String {
+        self.text
+            .chars()
+            .skip(self.columns.start)
+            .take(self.columns.len())
+            .collect()
+    }
+}
+
+// Where a slice starts within the text it was sliced from, in bytes.
+fn offset(text: &str, slice: &str) -> Option<usize> {
+    let text = text.as_bytes().as_ptr_range();
+    let slice = slice.as_bytes().as_ptr_range();
+    let within = text.start <= slice.start && slice.end <= text.end;
+    within.then(|| slice.start as usize - text.start as usize)
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let indent = " ".repeat(self.columns.start);
+        let carets = "^".repeat(self.columns.len().max(1));
+
+        writeln!(f, "{}", self.reason)?;
+        writeln!(f, "{gutter}--> {}:{}", self.line, self.columns.start + 1)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{number} | {}", self.text)?;
+        write!(f, "{gutter} | {indent}{carets}")
+    }
+}
+
+impl Error for ParseError {}
+
+impl PartialEq for ParseError {
+    fn eq(&self, other: &Self) -> bool {
+        self.line == other.line
+            && self.columns == other.columns
+            && self.text == other.text
+            && self.reason.to_string() == other.reason.to_string()
+    }
+}
+
+/// Parse a slice of a line, blaming the slice if it can't be parsed.
+pub fn value<T>(text: &str, slice: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Into<Reason>,
+{
+    slice
+        .parse()
+        .map_err(|error| ParseError::new(error, text, slice))
+}
+
+/// Parse each line of the input, numbering the line of any error.
+pub fn lines<T, L: AsRef<str>>(
+    lines: impl IntoIterator<Item = L>,
+    mut parse: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(number, line)| parse(line.as_ref()).map_err(|error| error.on_line(number + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::ParseIntError;
+
+    use super::{lines, value, ParseError};
+
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    #[test]
+    fn rendering() {
+        let text = "Game x: 3 blue";
+        let error = ParseError::new("invalid game ID", text, &text[5..6]).on_line(12);
+        let expected = "\
+invalid game ID
+  --> 12:6
+   |
+12 | Game x: 3 blue
+   |      ^";
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[test_case(0..3, 0..3, "abc" ; "start")]
+    #[test_case(4..7, 4..7, "def" ; "middle")]
+    #[test_case(8..8, 8..8, "" ; "end")]
+    fn columns(slice: std::ops::Range<usize>, expected: std::ops::Range<usize>, offending: &str) {
+        let text = "abc def ";
+        let error = ParseError::new("oops", text, &text[slice]);
+        assert_eq!(error.columns, expected);
+        assert_eq!(error.offending(), offending);
+    }
+
+    #[test]
+    fn columns_are_characters() {
+        let text = "día 7";
+        let error = ParseError::new("oops", text, &text[5..]);
+        assert_eq!(error.columns, 4..5);
+        assert_eq!(error.offending(), "7");
+    }
+
+    #[test]
+    fn not_a_slice() {
+        let error = ParseError::new("oops", "abc", "b");
+        assert_eq!(error.columns, 0..3);
+    }
+
+    #[test]
+    fn end() {
+        let error = ParseError::end("missing", "abc");
+        assert_eq!(error.columns, 3..3);
+        assert!(error.to_string().ends_with("1 | abc\n  |    ^"));
+    }
+
+    #[test]
+    fn parsing_values() {
+        let text = "move 1x";
+        assert_eq!(value::<u32>(text, &text[5..6]), Ok(1));
+
+        let error = value::<u32>(text, &text[5..7]).unwrap_err();
+        assert_eq!(error.columns, 5..7);
+        assert!(error.reason_is::<ParseIntError>().is_some());
+    }
+
+    #[test]
+    fn numbering_lines() {
+        let parse = |line: &str| value::<u32>(line, line);
+        assert_eq!(lines(["1", "2"], parse), Ok(vec![1, 2]));
+
+        let error = lines(["1", "2", "x"], parse).unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.offending(), "x");
+    }
+}
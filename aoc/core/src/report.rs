@@ -0,0 +1,186 @@
+use std::{
+    fmt::Display,
+    io,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{Answer, Error, Solution};
+
+/// How answers are printed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// One line per part, for people
+    #[default]
+    Text,
+    /// One JSON object per part, one per line
+    Json,
+    /// One CSV row per part, after a header
+    Csv,
+}
+
+/// The outcome of solving a single part of a puzzle.
+///
+/// A part which hasn't been solved yet has neither an answer nor an error.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// How long solving the part took, not including parsing.
+    pub elapsed_ns: u128,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn new(year: u16, day: u8, part: u8, answer: Answer, elapsed: Duration) -> Self {
+        let (answer, error) = match answer {
+            Ok(answer) => (Some(answer), None),
+            Err(Error::Unimplemented) => (None, None),
+            Err(error) => (None, Some(error.to_string())),
+        };
+        Self {
+            year,
+            day,
+            part,
+            answer,
+            elapsed_ns: elapsed.as_nanos(),
+            error,
+        }
+    }
+
+    /// Time how long it takes to solve a part.
+    pub fn timed<A: Display>(
+        year: u16,
+        day: u8,
+        part: u8,
+        solve: impl FnOnce() -> Result<A, Error>,
+    ) -> Self {
+        let start = Instant::now();
+        let answer = solve().map(|answer| answer.to_string());
+        Self::new(year, day, part, answer, start.elapsed())
+    }
+
+    /// Records for both parts of a puzzle which couldn't be solved at all,
+    /// e.g. because its input couldn't be read or parsed.
+    pub fn failed(year: u16, day: u8, error: impl Display) -> [Self; 2] {
+        [1, 2].map(|part| Self {
+            year,
+            day,
+            part,
+            answer: None,
+            elapsed_ns: 0,
+            error: Some(error.to_string()),
+        })
+    }
+}
+
+/// Parse the puzzle input once and then time solving each part with it.
+pub fn records<S: Solution>(input: &str) -> [Record; 2] {
+    match S::parse(input) {
+        Ok(input) => [
+            Record::timed(S::YEAR, S::DAY, 1, || S::part1(&input)),
+            Record::timed(S::YEAR, S::DAY, 2, || S::part2(&input)),
+        ],
+        Err(error) => Record::failed(S::YEAR, S::DAY, error),
+    }
+}
+
+/// Writes records in a given [`Format`].
+pub enum Writer<W: io::Write> {
+    Text(W),
+    Json(W),
+    Csv(Box<csv::Writer<W>>),
+}
+
+impl<W: io::Write> Writer<W> {
+    pub fn new(format: Format, writer: W) -> Self {
+        match format {
+            Format::Text => Self::Text(writer),
+            Format::Json => Self::Json(writer),
+            Format::Csv => Self::Csv(Box::new(csv::Writer::from_writer(writer))),
+        }
+    }
+
+    pub fn write(&mut self, record: &Record) -> anyhow::Result<()> {
+        match self {
+            Self::Text(writer) => {
+                let name = format!("{}/{:02} Part {}", record.year, record.day, record.part);
+                match (&record.answer, &record.error) {
+                    (_, Some(error)) => writeln!(writer, "{name} Error: {error}")?,
+                    (Some(answer), None) => writeln!(writer, "{name}: {answer}")?,
+                    (None, None) => writeln!(writer, "{name}: N/A")?,
+                }
+            }
+            Self::Json(writer) => {
+                serde_json::to_writer(&mut *writer, record)?;
+                writeln!(writer)?;
+            }
+            Self::Csv(writer) => writer.serialize(record)?,
+        }
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Text(writer) | Self::Json(writer) => writer.flush(),
+            Self::Csv(writer) => writer.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Format, Record, Writer};
+    use crate::Error;
+
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    fn records() -> [Record; 3] {
+        let elapsed = Duration::from_micros(42);
+        [
+            Record::new(2021, 1, 1, Ok("7".into()), elapsed),
+            Record::new(2021, 1, 2, Err(Error::Unimplemented), elapsed),
+            Record::new(2021, 2, 1, Err(Error::msg("oops, \"quoted\"")), elapsed),
+        ]
+    }
+
+    #[test_case(Format::Text, "\
+2021/01 Part 1: 7
+2021/01 Part 2: N/A
+2021/02 Part 1 Error: oops, \"quoted\"
+" ; "text")]
+    #[test_case(Format::Json, r#"{"year":2021,"day":1,"part":1,"answer":"7","elapsed_ns":42000,"error":null}
+{"year":2021,"day":1,"part":2,"answer":null,"elapsed_ns":42000,"error":null}
+{"year":2021,"day":2,"part":1,"answer":null,"elapsed_ns":42000,"error":"oops, \"quoted\""}
+"# ; "json")]
+    #[test_case(Format::Csv, r#"year,day,part,answer,elapsed_ns,error
+2021,1,1,7,42000,
+2021,1,2,,42000,
+2021,2,1,,42000,"oops, ""quoted"""
+"# ; "csv")]
+    fn writing(format: Format, expected: &str) -> anyhow::Result<()> {
+        let mut output = vec![];
+        let mut writer = Writer::new(format, &mut output);
+        for record in records() {
+            writer.write(&record)?;
+        }
+        writer.flush()?;
+        drop(writer);
+        assert_eq!(String::from_utf8(output)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn failed() {
+        let [part1, part2] = Record::failed(2021, 1, "bad input");
+        assert_eq!((part1.part, part2.part), (1, 2));
+        assert_eq!(part1.error.as_deref(), Some("bad input"));
+        assert_eq!(part1.answer, None);
+    }
+}
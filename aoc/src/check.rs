@@ -0,0 +1,117 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path};
+
+use aoc_core::{Answer, Error};
+use serde::Deserialize;
+
+/// The accepted answers to a single day's input.txt. Parts which haven't been
+/// solved yet have no accepted answer.
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Accepted {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// Every accepted answer, keyed by "year/day" (e.g. "2021/01").
+pub type AcceptedAnswers = BTreeMap<String, Accepted>;
+
+pub fn load(path: &Path) -> anyhow::Result<AcceptedAnswers> {
+    let answers = fs::read_to_string(path)?;
+    Ok(toml::from_str(&answers)?)
+}
+
+/// How a part's answer compares to its accepted answer.
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    /// The answer matches the accepted answer.
+    Correct,
+    /// The answer doesn't match the accepted answer.
+    Incorrect { expected: String, actual: String },
+    /// There's an answer, but no accepted answer to compare it to.
+    Missing(String),
+    /// The part hasn't been solved yet.
+    Unimplemented,
+    /// The part failed to produce an answer.
+    Failed(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "ok"),
+            Self::Incorrect { expected, actual } => {
+                write!(f, "INCORRECT expected {expected}, but got {actual}")
+            }
+            Self::Missing(actual) => write!(f, "missing accepted answer for {actual}"),
+            Self::Unimplemented => write!(f, "unimplemented"),
+            Self::Failed(error) => write!(f, "FAILED {error}"),
+        }
+    }
+}
+
+pub fn status(answer: Answer, accepted: Option<&String>) -> Status {
+    match (answer, accepted) {
+        (Ok(actual), Some(expected)) if actual == *expected => Status::Correct,
+        (Ok(actual), Some(expected)) => Status::Incorrect {
+            expected: expected.clone(),
+            actual,
+        },
+        (Ok(actual), None) => Status::Missing(actual),
+        (Err(Error::Unimplemented), _) => Status::Unimplemented,
+        (Err(error), _) => Status::Failed(error.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{load, status, Status};
+    use crate::days;
+
+    use aoc_core::Error;
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    #[test_case(Ok("42".into()), Some("42"), Status::Correct ; "correct")]
+    #[test_case(
+        Ok("42".into()), Some("24"),
+        Status::Incorrect { expected: "24".into(), actual: "42".into() }
+        ; "incorrect"
+    )]
+    #[test_case(Ok("42".into()), None, Status::Missing("42".into()) ; "missing")]
+    #[test_case(Err(Error::Unimplemented), None, Status::Unimplemented ; "unimplemented")]
+    #[test_case(Err(Error::msg("oops")), Some("42"), Status::Failed("oops".into()) ; "failed")]
+    fn statuses(answer: Result<String, Error>, accepted: Option<&str>, expected: Status) {
+        let accepted = accepted.map(String::from);
+        assert_eq!(status(answer, accepted.as_ref()), expected);
+    }
+
+    // Re-run every day against its real input and make sure nothing has
+    // regressed. Unimplemented parts and missing answers don't fail the test.
+    #[test]
+    fn accepted_answers() -> anyhow::Result<()> {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let accepted = load(&root.join("answers.toml"))?;
+
+        let mut failures = vec![];
+        for day in days::DAYS {
+            let input = day.input(&root.join(".."))?;
+            let answers = (day.solve)(&input).map_err(|e| anyhow::anyhow!("{e}"))?;
+            let expected = accepted.get(&day.name());
+            let parts = [
+                (1, answers.part1, expected.and_then(|a| a.part1.as_ref())),
+                (2, answers.part2, expected.and_then(|a| a.part2.as_ref())),
+            ];
+            for (part, answer, expected) in parts {
+                let status = status(answer, expected);
+                if matches!(status, Status::Incorrect { .. } | Status::Failed(_)) {
+                    failures.push(format!("{} Part {part}: {status}", day.name()));
+                }
+            }
+        }
+
+        assert_eq!(failures, Vec::<String>::new());
+        Ok(())
+    }
+}
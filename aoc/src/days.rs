@@ -0,0 +1,161 @@
+use std::{any::Any, path::Path};
+
+use aoc_core::{
+    input::{self, Source},
+    report::Record,
+    Answer, Answers, Error, Solution,
+};
+
+/// A single puzzle which can be solved from its raw input.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub solve: fn(&str) -> Result<Answers, Error>,
+    /// Solve both parts, timing each of them.
+    pub records: fn(&str) -> [Record; 2],
+    /// Parse the input on its own, for timing each phase separately.
+    pub parse: fn(&str) -> Result<Parsed, Error>,
+    /// Solve part 1 with input from [`Day::parse`].
+    pub part1: fn(&Parsed) -> Answer,
+    /// Solve part 2 with input from [`Day::parse`].
+    pub part2: fn(&Parsed) -> Answer,
+}
+
+/// A day's parsed input with its type erased so that every day can share the
+/// same [`Day`] signature.
+pub type Parsed = Box<dyn Any>;
+
+impl Day {
+    /// The puzzle's name, e.g. "2021/01".
+    pub fn name(&self) -> String {
+        format!("{}/{:02}", self.year, self.day)
+    }
+
+    /// Read the puzzle's input.txt from the repository root.
+    pub fn input(&self, root: &Path) -> Result<String, input::Error> {
+        let path = root
+            .join(self.year.to_string())
+            .join(format!("{:02}", self.day))
+            .join("input.txt");
+        Source::Path(path).read()
+    }
+}
+
+const fn day<S: Solution>() -> Day
+where
+    S::Input: 'static,
+{
+    Day {
+        year: S::YEAR,
+        day: S::DAY,
+        solve: aoc_core::solve::<S>,
+        records: aoc_core::report::records::<S>,
+        parse: parse::<S>,
+        part1: part1::<S>,
+        part2: part2::<S>,
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<Parsed, Error>
+where
+    S::Input: 'static,
+{
+    Ok(Box::new(S::parse(input)?))
+}
+
+// NOTE: These can only be given input from the same day's parse, so failing
+// to downcast is a bug rather than an error.
+fn part1<S: Solution>(input: &Parsed) -> Answer
+where
+    S::Input: 'static,
+{
+    let input = input.downcast_ref().expect("input is from another day");
+    S::part1(input).map(|answer| answer.to_string())
+}
+
+fn part2<S: Solution>(input: &Parsed) -> Answer
+where
+    S::Input: 'static,
+{
+    let input = input.downcast_ref().expect("input is from another day");
+    S::part2(input).map(|answer| answer.to_string())
+}
+
+pub const DAYS: &[Day] = &[
+    day::<aoc_2015_01::Puzzle>(),
+    day::<aoc_2015_02::Puzzle>(),
+    day::<aoc_2015_03::Puzzle>(),
+    day::<aoc_2016_01::Puzzle>(),
+    day::<aoc_2017_01::Puzzle>(),
+    day::<aoc_2018_01::Puzzle>(),
+    day::<aoc_2018_02::Puzzle>(),
+    day::<aoc_2019_01::Puzzle>(),
+    day::<aoc_2019_02::Puzzle>(),
+    day::<aoc_2020_01::Puzzle>(),
+    day::<aoc_2020_02::Puzzle>(),
+    day::<aoc_2020_03::Puzzle>(),
+    day::<aoc_2021_01::Puzzle>(),
+    day::<aoc_2021_02::Puzzle>(),
+    day::<aoc_2021_03::Puzzle>(),
+    day::<aoc_2021_10::Puzzle>(),
+    day::<aoc_2022_01::Puzzle>(),
+    day::<aoc_2023_01::Puzzle>(),
+    day::<aoc_2023_02::Puzzle>(),
+    day::<aoc_2024_01::Puzzle>(),
+];
+
+/// Select the puzzles for a given year and, optionally, a given day. If no
+/// year is given then every puzzle is selected.
+pub fn select(year: Option<u16>, day: Option<u8>) -> Vec<&'static Day> {
+    DAYS.iter()
+        .filter(|d| year.is_none_or(|year| d.year == year))
+        .filter(|d| day.is_none_or(|day| d.day == day))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{select, DAYS};
+
+    use std::path::Path;
+
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    #[test_case(None, None, DAYS.len() ; "all")]
+    #[test_case(Some(2021), None, 4 ; "year")]
+    #[test_case(Some(2021), Some(10), 1 ; "year and day")]
+    #[test_case(Some(2021), Some(25), 0 ; "missing day")]
+    #[test_case(Some(2014), None, 0 ; "missing year")]
+    fn selection(year: Option<u16>, day: Option<u8>, expected: usize) {
+        assert_eq!(select(year, day).len(), expected);
+    }
+
+    #[test]
+    fn days_are_unique() {
+        let mut days = DAYS.iter().map(|d| (d.year, d.day)).collect::<Vec<_>>();
+        days.dedup();
+        assert_eq!(days.len(), DAYS.len());
+    }
+
+    // Parsing and solving each part separately should give the same answers
+    // as solving everything at once.
+    #[test]
+    fn phases_match_solve() -> anyhow::Result<()> {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        for day in DAYS {
+            let input = day.input(&root)?;
+            let answers = (day.solve)(&input).map_err(|e| anyhow::anyhow!("{e}"))?;
+            let parsed = (day.parse)(&input).map_err(|e| anyhow::anyhow!("{e}"))?;
+            assert_eq!(
+                format!("{:?}", (day.part1)(&parsed)),
+                format!("{:?}", answers.part1)
+            );
+            assert_eq!(
+                format!("{:?}", (day.part2)(&parsed)),
+                format!("{:?}", answers.part2)
+            );
+        }
+        Ok(())
+    }
+}
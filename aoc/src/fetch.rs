@@ -0,0 +1,231 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Where puzzle inputs are fetched from unless told otherwise.
+pub const ENDPOINT: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/reillysiemens/advent-of-rust";
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{url} responded with {status}")]
+    Status { url: String, status: u16 },
+    #[error("Failed to request {url}: {message}")]
+    Transport { url: String, message: String },
+    #[error("Failed to cache {}: {source}", path.display())]
+    Cache { path: PathBuf, source: io::Error },
+}
+
+/// An HTTP client which can fetch a puzzle input.
+pub trait Http {
+    /// Get the body of the given URL, authenticating with a session token.
+    fn get(&self, url: &str, session: &str) -> Result<String, Error>;
+}
+
+/// Fetches inputs with [ureq](https://docs.rs/ureq).
+#[derive(Debug, Default)]
+pub struct Ureq;
+
+impl Http for Ureq {
+    fn get(&self, url: &str, session: &str) -> Result<String, Error> {
+        let response = ureq::get(url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|error| match error {
+                ureq::Error::Status(status, _) => Error::Status {
+                    url: url.into(),
+                    status,
+                },
+                ureq::Error::Transport(transport) => Error::Transport {
+                    url: url.into(),
+                    message: transport.to_string(),
+                },
+            })?;
+        response.into_string().map_err(|error| Error::Transport {
+            url: url.into(),
+            message: error.to_string(),
+        })
+    }
+}
+
+/// Whether an input had to be downloaded.
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+pub struct Fetcher<H: Http> {
+    http: H,
+    endpoint: String,
+    session: String,
+}
+
+impl<H: Http> Fetcher<H> {
+    pub fn new(http: H, endpoint: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            http,
+            endpoint: endpoint.into(),
+            session: session.into(),
+        }
+    }
+
+    pub fn url(&self, year: u16, day: u8) -> String {
+        let endpoint = self.endpoint.trim_end_matches('/');
+        format!("{endpoint}/{year}/day/{day}/input")
+    }
+
+    /// Fetch a day's input into its input.txt under the repository root. An
+    /// input which has already been fetched is never fetched again.
+    pub fn fetch(&self, root: &Path, year: u16, day: u8) -> Result<Fetched, Error> {
+        let directory = root.join(year.to_string()).join(format!("{day:02}"));
+        let path = directory.join("input.txt");
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        // Write somewhere else first and then move it into place, so an input
+        // which is only partly written is never mistaken for a cached one.
+        let input = self.http.get(&self.url(year, day), &self.session)?;
+        let partial = directory.join("input.txt.part");
+        fs::create_dir_all(&directory)
+            .and_then(|()| fs::write(&partial, input))
+            .and_then(|()| fs::rename(&partial, &path))
+            .map_err(|source| {
+                let _ = fs::remove_file(&partial);
+                Error::Cache {
+                    path: path.clone(),
+                    source,
+                }
+            })?;
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::Cell,
+        fs,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::{Error, Fetched, Fetcher, Http, Ureq};
+
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    // Stands in for the real endpoint, answering a single request with the
+    // given status and body. Returns the endpoint and a handle which yields
+    // the request line and headers once the request has been answered.
+    fn serve(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request = BufReader::new(&stream)
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect();
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+        (endpoint, handle)
+    }
+
+    // Counts requests rather than making them.
+    struct Counting(Cell<usize>);
+
+    impl Http for Counting {
+        fn get(&self, _url: &str, _session: &str) -> Result<String, Error> {
+            self.0.set(self.0.get() + 1);
+            Ok("1\n2\n3\n".into())
+        }
+    }
+
+    #[test_case("https://adventofcode.com" ; "endpoint")]
+    #[test_case("https://adventofcode.com/" ; "trailing slash")]
+    fn url(endpoint: &str) {
+        let fetcher = Fetcher::new(Ureq, endpoint, "token");
+        assert_eq!(
+            fetcher.url(2024, 2),
+            "https://adventofcode.com/2024/day/2/input"
+        );
+    }
+
+    #[test]
+    fn downloading() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        let (endpoint, server) = serve("200 OK", "1\n2\n3\n");
+
+        let fetcher = Fetcher::new(Ureq, endpoint, "token");
+        let path = root.path().join("2024").join("02").join("input.txt");
+        assert_eq!(
+            fetcher.fetch(root.path(), 2024, 2)?,
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(path)?, "1\n2\n3\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2024/day/2/input HTTP/1.1");
+        assert!(request.iter().any(|h| h == "Cookie: session=token"));
+        Ok(())
+    }
+
+    #[test]
+    fn never_refetching() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        let fetcher = Fetcher::new(Counting(Cell::new(0)), "", "token");
+        let path = root.path().join("2024").join("02").join("input.txt");
+
+        assert_eq!(
+            fetcher.fetch(root.path(), 2024, 2)?,
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fetcher.fetch(root.path(), 2024, 2)?, Fetched::Cached(path));
+        assert_eq!(fetcher.http.0.get(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn failing_to_cache() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        let directory = root.path().join("2024").join("02");
+        // A directory in the way means the input can't be written.
+        fs::create_dir_all(directory.join("input.txt.part"))?;
+
+        let fetcher = Fetcher::new(Counting(Cell::new(0)), "", "token");
+        let result = fetcher.fetch(root.path(), 2024, 2);
+        assert!(matches!(result, Err(Error::Cache { .. })));
+        assert!(!directory.join("input.txt").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn bad_status() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        let (endpoint, server) = serve("400 Bad Request", "Please log in.");
+
+        let fetcher = Fetcher::new(Ureq, endpoint, "expired");
+        let result = fetcher.fetch(root.path(), 2024, 2);
+        server.join().unwrap();
+
+        assert!(matches!(result, Err(Error::Status { status: 400, .. })));
+        assert!(fs::read_dir(root.path())?.next().is_none());
+        Ok(())
+    }
+}
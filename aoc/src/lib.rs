@@ -0,0 +1,4 @@
+pub mod check;
+pub mod days;
+pub mod fetch;
+pub mod scaffold;
@@ -0,0 +1,255 @@
+use std::{fs, io, path::PathBuf};
+
+use anyhow::{bail, Context};
+use aoc::{
+    check,
+    days::{self, Day},
+    fetch::{self, Fetched, Fetcher},
+    scaffold,
+};
+use aoc_core::{
+    report::{Format, Record, Writer},
+    Answers,
+};
+use clap::{ArgGroup, Args as ClapArgs, Parser, Subcommand};
+
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve puzzles using their input.txt and print the answers
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Solve puzzles and compare the answers against the accepted answers
+    Check {
+        #[command(flatten)]
+        selection: Selection,
+        /// The file containing the accepted answers
+        #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml"))]
+        answers: PathBuf,
+    },
+    /// Print the fenced example blocks from a day's README.md
+    Examples {
+        /// The year of the puzzle
+        year: u16,
+        /// The day of the puzzle
+        day: u8,
+        /// The repository root containing the year/day directories
+        #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/.."))]
+        root: PathBuf,
+    },
+    /// Download a day's input.txt, unless it's already been downloaded
+    Fetch {
+        /// The year of the puzzle
+        year: u16,
+        /// The day of the puzzle
+        day: u8,
+        /// The repository root containing the year/day directories
+        #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/.."))]
+        root: PathBuf,
+        /// The session cookie of a logged in Advent of Code user
+        #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+        session: String,
+        /// Where to download inputs from
+        #[arg(long, env = "AOC_ENDPOINT", default_value = fetch::ENDPOINT)]
+        endpoint: String,
+    },
+    /// Create a new day's crate from the template
+    New {
+        /// The year of the new puzzle
+        year: u16,
+        /// The day of the new puzzle
+        day: u8,
+        /// The repository root containing the year/day directories
+        #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/.."))]
+        root: PathBuf,
+    },
+}
+
+#[derive(Debug, ClapArgs)]
+#[command(group(ArgGroup::new("selection").required(true).args(["year", "all"])))]
+struct Selection {
+    /// The year to solve
+    year: Option<u16>,
+    /// The day to solve, otherwise every day in the year
+    #[arg(requires = "year")]
+    day: Option<u8>,
+    /// Solve every puzzle
+    #[arg(long)]
+    all: bool,
+    /// The repository root containing the year/day directories
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/.."))]
+    root: PathBuf,
+}
+
+impl Selection {
+    fn days(&self) -> anyhow::Result<Vec<&'static Day>> {
+        let selected = days::select(self.year, self.day);
+        if selected.is_empty() {
+            bail!("No puzzles were found for the given year and day");
+        }
+        Ok(selected)
+    }
+
+    /// Solve every selected puzzle, reporting any puzzle whose input couldn't
+    /// be read or parsed. Returns the number of such failures.
+    fn solve(&self, mut report: impl FnMut(&Day, Answers)) -> anyhow::Result<usize> {
+        let mut failures = 0;
+        for puzzle in self.days()? {
+            let name = puzzle.name();
+            let input = match puzzle.input(&self.root) {
+                Ok(input) => input,
+                Err(error) => {
+                    eprintln!("{name} Error: {error}");
+                    failures += 1;
+                    continue;
+                }
+            };
+
+            match (puzzle.solve)(&input) {
+                Ok(answers) => report(puzzle, answers),
+                Err(error) => {
+                    eprintln!("{name} Error: {error}");
+                    failures += 1;
+                }
+            }
+        }
+        Ok(failures)
+    }
+}
+
+fn run(selection: Selection, format: Format) -> anyhow::Result<()> {
+    let mut writer = Writer::new(format, io::stdout().lock());
+    let mut failures = 0;
+    for puzzle in selection.days()? {
+        let records = match puzzle.input(&selection.root) {
+            Ok(input) => (puzzle.records)(&input),
+            Err(error) => Record::failed(puzzle.year, puzzle.day, error),
+        };
+        for record in records {
+            if record.error.is_some() {
+                failures += 1;
+            }
+            writer.write(&record)?;
+        }
+    }
+    writer.flush()?;
+
+    if failures > 0 {
+        bail!("{failures} part(s) failed");
+    }
+    Ok(())
+}
+
+#[derive(Debug, Default)]
+struct Summary {
+    correct: usize,
+    incorrect: usize,
+    missing: usize,
+    unimplemented: usize,
+    failed: usize,
+}
+
+fn check(selection: Selection, answers: PathBuf) -> anyhow::Result<()> {
+    let accepted = check::load(&answers)?;
+
+    let mut summary = Summary::default();
+    summary.failed += selection.solve(|puzzle, answers| {
+        let name = puzzle.name();
+        let expected = accepted.get(&name);
+        let parts = [
+            (1, answers.part1, expected.and_then(|a| a.part1.as_ref())),
+            (2, answers.part2, expected.and_then(|a| a.part2.as_ref())),
+        ];
+        for (part, answer, expected) in parts {
+            let status = check::status(answer, expected);
+            match status {
+                check::Status::Correct => summary.correct += 1,
+                check::Status::Incorrect { .. } => summary.incorrect += 1,
+                check::Status::Missing(_) => summary.missing += 1,
+                check::Status::Unimplemented => summary.unimplemented += 1,
+                check::Status::Failed(_) => summary.failed += 1,
+            }
+            println!("{name} Part {part}: {status}");
+        }
+    })?;
+
+    let Summary {
+        correct,
+        incorrect,
+        missing,
+        unimplemented,
+        failed,
+    } = summary;
+    println!(
+        "\n{correct} correct, {incorrect} incorrect, {missing} missing, \
+         {unimplemented} unimplemented, {failed} failed"
+    );
+
+    if incorrect + failed > 0 {
+        bail!("{} part(s) regressed", incorrect + failed);
+    }
+    Ok(())
+}
+
+fn examples(root: PathBuf, year: u16, day: u8) -> anyhow::Result<()> {
+    let path = root
+        .join(year.to_string())
+        .join(format!("{day:02}"))
+        .join("README.md");
+    let readme =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    for (n, example) in aoc_core::examples::extract(&readme).iter().enumerate() {
+        println!("Example {n}:\n{example}\n");
+    }
+    Ok(())
+}
+
+fn fetch(
+    root: PathBuf,
+    year: u16,
+    day: u8,
+    session: String,
+    endpoint: String,
+) -> anyhow::Result<()> {
+    let fetcher = Fetcher::new(fetch::Ureq, endpoint, session);
+    match fetcher.fetch(&root, year, day)? {
+        Fetched::Cached(path) => println!("{} has already been fetched", path.display()),
+        Fetched::Downloaded(path) => println!("Fetched {}", path.display()),
+    }
+    Ok(())
+}
+
+fn new(root: PathBuf, year: u16, day: u8) -> anyhow::Result<()> {
+    let path = scaffold::create(&root, year, day)?;
+    let name = format!("aoc-{year}-{day:02}");
+    println!("Created {}", path.display());
+    println!("To solve it with the runner, add {name} to aoc/Cargo.toml and aoc/src/days.rs");
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    match args.command {
+        Command::Run { selection, format } => run(selection, format),
+        Command::Check { selection, answers } => check(selection, answers),
+        Command::Examples { year, day, root } => examples(root, year, day),
+        Command::Fetch {
+            year,
+            day,
+            root,
+            session,
+            endpoint,
+        } => fetch(root, year, day, session, endpoint),
+        Command::New { year, day, root } => new(root, year, day),
+    }
+}
@@ -0,0 +1,108 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+
+/// The files making up a new day, relative to its directory.
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/day/Cargo.toml")),
+    ("README.md", include_str!("../templates/day/README.md")),
+    ("src/lib.rs", include_str!("../templates/day/src/lib.rs")),
+    ("src/main.rs", include_str!("../templates/day/src/main.rs")),
+    (
+        "src/part1.rs",
+        include_str!("../templates/day/src/part1.rs"),
+    ),
+    (
+        "src/part2.rs",
+        include_str!("../templates/day/src/part2.rs"),
+    ),
+];
+
+/// Fill in a template's placeholders for the given year and day.
+pub fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{padded_day}}", &format!("{day:02}"))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Create a new day's crate under the repository root, refusing to touch a
+/// day which already exists. Returns the path to the new crate.
+pub fn create(root: &Path, year: u16, day: u8) -> anyhow::Result<PathBuf> {
+    if year < 2015 {
+        bail!("There was no Advent of Code in {year}");
+    }
+    if !(1..=25).contains(&day) {
+        bail!("Day {day} isn't between 1 and 25");
+    }
+
+    let path = root.join(year.to_string()).join(format!("{day:02}"));
+    if path.exists() {
+        bail!("{} already exists", path.display());
+    }
+
+    for (name, template) in TEMPLATES {
+        let file = path.join(name);
+        if let Some(parent) = file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&file, render(template, year, day))
+            .with_context(|| format!("Failed to write {}", file.display()))?;
+    }
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{create, render, TEMPLATES};
+
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    #[test_case("aoc-{{year}}-{{padded_day}}", "aoc-2024-02" ; "package name")]
+    #[test_case("aoc_{{year}}_{{padded_day}}", "aoc_2024_02" ; "crate name")]
+    #[test_case("const DAY: u8 = {{day}};", "const DAY: u8 = 2;" ; "day")]
+    #[test_case("println!(\"{part1}\")", "println!(\"{part1}\")" ; "format string")]
+    fn rendering(template: &str, expected: &str) {
+        assert_eq!(render(template, 2024, 2), expected);
+    }
+
+    #[test]
+    fn creating() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        let path = create(root.path(), 2024, 2)?;
+        assert_eq!(path, root.path().join("2024").join("02"));
+
+        for (name, _) in TEMPLATES {
+            let contents = fs::read_to_string(path.join(name))?;
+            assert!(!contents.contains("{{"), "{name} has a placeholder");
+        }
+        let manifest = fs::read_to_string(path.join("Cargo.toml"))?;
+        assert!(manifest.contains("name = \"aoc-2024-02\""));
+        Ok(())
+    }
+
+    #[test]
+    fn existing_day() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        fs::create_dir_all(root.path().join("2024").join("02"))?;
+        assert!(create(root.path(), 2024, 2).is_err());
+        Ok(())
+    }
+
+    #[test_case(2014, 1 ; "before advent of code")]
+    #[test_case(2024, 0 ; "day zero")]
+    #[test_case(2024, 26 ; "after christmas")]
+    fn invalid_day(year: u16, day: u8) -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        assert!(create(root.path(), year, day).is_err());
+        assert!(fs::read_dir(root.path())?.next().is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+pub mod part1;
+pub mod part2;
+
+use aoc_core::{Error, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const YEAR: u16 = {{year}};
+    const DAY: u8 = {{day}};
+
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, Error> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    // TODO: Return Ok(part1::solve(input)?) once part 1 is solved.
+    fn part1(_input: &Self::Input) -> Result<Self::Part1, Error> {
+        Err(Error::Unimplemented)
+    }
+
+    // TODO: Return Ok(part2::solve(input)?) once part 2 is solved.
+    fn part2(_input: &Self::Input) -> Result<Self::Part2, Error> {
+        Err(Error::Unimplemented)
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use aoc_{{year}}_{{padded_day}}::Puzzle;
+
+fn main() -> ExitCode {
+    aoc_core::cli::main::<Puzzle>()
+}
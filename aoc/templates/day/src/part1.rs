@@ -0,0 +1,27 @@
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum Error {
+    #[error("Part 1 hasn't been solved yet")]
+    Unsolved,
+}
+
+pub fn solve(lines: impl IntoIterator<Item = impl AsRef<str>>) -> anyhow::Result<u32> {
+    let _lines: Vec<_> = lines.into_iter().collect();
+    Err(Error::Unsolved.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::solve;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn solution() -> anyhow::Result<()> {
+        // TODO: Use the example's answer from the puzzle description.
+        let expected = 0;
+        let puzzle = aoc_core::example!(0).lines();
+        let actual = solve(puzzle)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+}